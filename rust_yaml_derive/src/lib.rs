@@ -0,0 +1,135 @@
+//! `#[derive(UnityValue)]` for field structs.
+//!
+//! Hand-writing `from_yaml`/`to_godot` for every Unity component is mostly
+//! the same dozen lines repeated: pull a key out of the `Yaml::Hash`, coerce
+//! it, and emit the matching Godot property line. This macro generates both
+//! directions from field attributes instead:
+//!
+//! ```ignore
+//! #[derive(UnityValue)]
+//! struct UnityTransform {
+//!     #[unity(name = "m_LocalPosition")]
+//!     #[godot(name = "position")]
+//!     position: UnityVector3,
+//! }
+//! ```
+//!
+//! A field with no `#[unity(name = ...)]`/`#[godot(name = ...)]` attribute
+//! falls back to its Rust field name.
+
+use proc_macro::TokenStream;
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Path to the crate that defines `UnityValue`/`Yaml`, resolved relative to
+/// whichever crate is actually invoking `#[derive(UnityValue)]`. Inside
+/// `rust_yaml` itself this is `crate`; for a downstream crate depending on
+/// `rust_yaml` it's `::rust_yaml` (or whatever that crate renamed it to).
+fn host_crate_path() -> proc_macro2::TokenStream {
+    match crate_name("rust_yaml") {
+        Ok(FoundCrate::Itself) => quote! { crate },
+        Ok(FoundCrate::Name(name)) => {
+            let ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
+            quote! { ::#ident }
+        }
+        Err(_) => quote! { ::rust_yaml },
+    }
+}
+
+#[proc_macro_derive(UnityValue, attributes(unity, godot))]
+pub fn derive_unity_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let struct_name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => panic!("#[derive(UnityValue)] only supports structs with named fields"),
+        },
+        _ => panic!("#[derive(UnityValue)] only supports structs"),
+    };
+
+    let host_crate = host_crate_path();
+    let mut from_yaml_fields = Vec::new();
+    let mut to_godot_lines = Vec::new();
+
+    for field in fields {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let unity_name = attr_name(field, "unity").unwrap_or_else(|| field_ident.to_string());
+        let godot_name = attr_name(field, "godot").unwrap_or_else(|| field_ident.to_string());
+        let is_string = field_is_string(&field.ty);
+        let field_ty = &field.ty;
+
+        if is_string {
+            from_yaml_fields.push(quote! {
+                #field_ident: map.get(#unity_name)?.as_str()?.to_string(),
+            });
+            to_godot_lines.push(quote! {
+                out.push_str(&format!("{} = \"{}\"\n", #godot_name, self.#field_ident));
+            });
+        } else {
+            from_yaml_fields.push(quote! {
+                #field_ident: <#field_ty as #host_crate::translator::UnityValue>::from_yaml(map.get(#unity_name)?)?,
+            });
+            to_godot_lines.push(quote! {
+                out.push_str(&format!("{} = {}\n", #godot_name, self.#field_ident.to_godot()));
+            });
+        }
+    }
+
+    let expanded = quote! {
+        impl #host_crate::translator::UnityValue for #struct_name {
+            fn to_godot(&self) -> String {
+                let mut out = String::new();
+                #(#to_godot_lines)*
+                out
+            }
+
+            fn from_yaml(yaml: &#host_crate::yaml::Yaml) -> Option<Self> {
+                let map = match yaml {
+                    #host_crate::yaml::Yaml::Hash(map) => map,
+                    _ => return None,
+                };
+                Some(#struct_name {
+                    #(#from_yaml_fields)*
+                })
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Read the `name` argument out of a `#[unity(name = "...")]` /
+/// `#[godot(name = "...")]` attribute, if present.
+fn attr_name(field: &syn::Field, attr_ident: &str) -> Option<String> {
+    for attr in &field.attrs {
+        if !attr.path().is_ident(attr_ident) {
+            continue;
+        }
+        let mut found = None;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                found = Some(value.value());
+            }
+            Ok(())
+        });
+        if found.is_some() {
+            return found;
+        }
+    }
+    None
+}
+
+/// Whether a field's type is (textually) `String`, to decide between the
+/// plain-string and `UnityValue::from_yaml` code paths.
+fn field_is_string(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            return segment.ident == "String";
+        }
+    }
+    false
+}
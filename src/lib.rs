@@ -0,0 +1,6 @@
+pub mod yaml;
+pub mod translator;
+pub mod unity_math;
+pub mod unity_types;
+
+pub use rust_yaml_derive::UnityValue;
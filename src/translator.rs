@@ -1,6 +1,8 @@
 
-use crate::yaml::Yaml;
+use crate::yaml::{Yaml, YamlMap};
 use std::any::type_name;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 
 /// Core translation trait between Unity YAML and Godot TSCN
 pub trait UnityValue: Sized {
@@ -11,8 +13,323 @@ pub trait UnityValue: Sized {
     /// Returns `None` if parsing fails or fields are missing.
     fn from_yaml(yaml: &Yaml) -> Option<Self>;
 
+    /// Try to construct this Unity type from a parsed `.tscn` fragment.
+    /// Returns `None` if the fragment doesn't describe this type.
+    fn from_godot(tscn: &str) -> Option<Self> {
+        let _ = tscn;
+        None
+    }
+
+    /// Convert this Unity type back into the intermediate `Yaml` form, the
+    /// inverse of `from_yaml`. Used together with `from_godot` so a round
+    /// trip can be diffed against the original Unity document. The default
+    /// returns an empty `Hash`; it is only an inverse where a type overrides it.
+    fn to_yaml(&self) -> Yaml {
+        Yaml::Hash(YamlMap::new())
+    }
+
+    /// Like `to_godot`, but emits into a shared `SceneBuilder` instead of
+    /// returning a standalone fragment, so that any `ExtResource`/`SubResource`
+    /// this value needs get registered (and deduplicated) in the document's
+    /// resource tables rather than inlined. The default just forwards to
+    /// `to_godot`, which is fine for values that never reference a resource.
+    fn to_godot_scene(&self, builder: &mut SceneBuilder) -> String {
+        let _ = builder;
+        self.to_godot()
+    }
+
     // like Python’s type(obj) or C#’s obj.GetType()
     fn type_name(&self) -> &'static str {
         type_name::<Self>()
     }
+}
+
+/// Assembles a loadable `.tscn` file: a `[gd_scene ...]` header plus
+/// deduplicated `[ext_resource ...]`/`[sub_resource ...]` tables and the node
+/// tree, all referencing each other by stable `ExtResource("id")` /
+/// `SubResource("id")` ids instead of being inlined.
+#[derive(Debug, Default)]
+pub struct SceneBuilder {
+    ext_resources: Vec<(String, String, String)>, // (type, path, id)
+    sub_resources: Vec<(String, String, String)>, // (type, body, id)
+    nodes: Vec<String>,
+    next_sub_id: usize,
+    next_ext_id: usize,
+    uid: Option<String>,
+}
+
+impl SceneBuilder {
+    pub fn new() -> Self {
+        SceneBuilder::default()
+    }
+
+    /// Set the scene's `uid://` identifier, e.g. one read back from an
+    /// existing `.tscn`'s header so re-saving it doesn't mint a new one.
+    /// If never set, `build()` derives a placeholder from the scene's own
+    /// contents instead of leaving `uid` off the header entirely — it isn't
+    /// a real entry in Godot's resource-UID table, just a stable, non-empty
+    /// value so the header matches the format Godot actually writes.
+    pub fn with_uid(mut self, uid: impl Into<String>) -> Self {
+        self.uid = Some(uid.into());
+        self
+    }
+
+    /// Register an external resource (e.g. a texture or script) referenced by
+    /// path, deduplicating by `(type, path)`. Returns the id to use in
+    /// `ExtResource("id")`.
+    pub fn add_ext_resource(&mut self, res_type: &str, path: &str) -> String {
+        if let Some((_, _, id)) = self
+            .ext_resources
+            .iter()
+            .find(|(t, p, _)| t == res_type && p == path)
+        {
+            return id.clone();
+        }
+        self.next_ext_id += 1;
+        let id = format!("{}_{}", res_type.to_lowercase(), self.next_ext_id);
+        self.ext_resources
+            .push((res_type.to_string(), path.to_string(), id.clone()));
+        id
+    }
+
+    /// Register an inline sub-resource (e.g. a material or mesh), deduplicating
+    /// identical `(type, body)` pairs so the same resource is never emitted
+    /// twice. Returns the id to use in `SubResource("id")`.
+    pub fn add_sub_resource(&mut self, res_type: &str, body: &str) -> String {
+        if let Some((_, _, id)) = self
+            .sub_resources
+            .iter()
+            .find(|(t, b, _)| t == res_type && b == body)
+        {
+            return id.clone();
+        }
+        self.next_sub_id += 1;
+        let id = format!("{}_{}", res_type.to_lowercase(), self.next_sub_id);
+        self.sub_resources
+            .push((res_type.to_string(), body.to_string(), id.clone()));
+        id
+    }
+
+    /// Append an already-rendered `[node ...]` block (header plus properties)
+    /// to the scene.
+    pub fn add_node(&mut self, node_block: String) {
+        self.nodes.push(node_block);
+    }
+
+    /// Number of `[ext_resource]` + `[sub_resource]` blocks plus one for the
+    /// scene itself, i.e. Godot's `load_steps` header value.
+    pub fn load_steps(&self) -> usize {
+        self.ext_resources.len() + self.sub_resources.len() + 1
+    }
+
+    fn uid_or_placeholder(&self) -> String {
+        if let Some(uid) = &self.uid {
+            return uid.clone();
+        }
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.ext_resources.hash(&mut hasher);
+        self.sub_resources.hash(&mut hasher);
+        self.nodes.hash(&mut hasher);
+        format!("uid://{:x}", hasher.finish())
+    }
+
+    /// Serialize the whole document: `[gd_scene]` header, resource tables,
+    /// then the node tree, in the order Godot expects.
+    pub fn build(&self) -> String {
+        let mut out = String::new();
+        out.push_str(&format!(
+            "[gd_scene load_steps={} format=3 uid=\"{}\"]\n\n",
+            self.load_steps(),
+            self.uid_or_placeholder()
+        ));
+
+        for (res_type, path, id) in &self.ext_resources {
+            out.push_str(&format!(
+                "[ext_resource type=\"{}\" path=\"{}\" id=\"{}\"]\n",
+                res_type, path, id
+            ));
+        }
+        if !self.ext_resources.is_empty() {
+            out.push('\n');
+        }
+
+        for (res_type, body, id) in &self.sub_resources {
+            out.push_str(&format!("[sub_resource type=\"{}\" id=\"{}\"]\n", res_type, id));
+            out.push_str(body);
+            out.push('\n');
+        }
+        if !self.sub_resources.is_empty() {
+            out.push('\n');
+        }
+
+        for node in &self.nodes {
+            out.push_str(node);
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+/// One line of a parsed `.tscn` fragment: either a section header such as
+/// `[node name="Foo" type="Node2D"]` or a `key = value` property line.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TscnLine {
+    /// A `[section ...]` header, split into its tag (`node`, `sub_resource`, ...)
+    /// and its `key="value"` attributes.
+    Header { tag: String, attrs: HashMap<String, String> },
+    /// A `key = value` property line inside the current section.
+    Property { key: String, value: String },
+}
+
+/// Tokenize a `.tscn` fragment into headers and property lines so
+/// `UnityValue::from_godot` implementors can pull out the fields they need
+/// without re-deriving this parsing themselves.
+pub fn tokenize_tscn(tscn: &str) -> Vec<TscnLine> {
+    let mut lines = Vec::new();
+
+    for raw in tscn.lines() {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() || trimmed.starts_with(';') {
+            continue;
+        }
+
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            lines.push(parse_tscn_header(trimmed));
+            continue;
+        }
+
+        if let Some(idx) = trimmed.find('=') {
+            let key = trimmed[..idx].trim().to_string();
+            let value = trimmed[idx + 1..].trim().to_string();
+            lines.push(TscnLine::Property { key, value });
+        }
+    }
+
+    lines
+}
+
+/// Parse a `[node name="Foo" type="Node2D"]`-style header line into its tag
+/// and attribute map. Attribute values keep their surrounding quotes stripped;
+/// values that aren't quoted (e.g. `index=0`) are kept as-is.
+fn parse_tscn_header(line: &str) -> TscnLine {
+    let inner = line.trim_start_matches('[').trim_end_matches(']');
+    let mut parts = inner.split_whitespace();
+    let tag = parts.next().unwrap_or("").to_string();
+
+    let mut attrs = HashMap::new();
+    let rest: String = parts.collect::<Vec<_>>().join(" ");
+    for attr in split_tscn_attrs(&rest) {
+        if let Some(idx) = attr.find('=') {
+            let key = attr[..idx].trim().to_string();
+            let value = attr[idx + 1..].trim().trim_matches('"').to_string();
+            attrs.insert(key, value);
+        }
+    }
+
+    TscnLine::Header { tag, attrs }
+}
+
+/// Split a header's attribute list on whitespace, but keep `key="a b c"`
+/// together even when the quoted value itself contains spaces.
+fn split_tscn_attrs(rest: &str) -> Vec<String> {
+    let mut attrs = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for ch in rest.chars() {
+        if ch == '"' {
+            in_quotes = !in_quotes;
+            current.push(ch);
+            continue;
+        }
+        if ch.is_whitespace() && !in_quotes {
+            if !current.is_empty() {
+                attrs.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        current.push(ch);
+    }
+    if !current.is_empty() {
+        attrs.push(current);
+    }
+
+    attrs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scene_builder_dedups_ext_and_sub_resources_by_identity() {
+        let mut builder = SceneBuilder::new();
+        let tex_id = builder.add_ext_resource("Texture2D", "res://icon.png");
+        let tex_id_again = builder.add_ext_resource("Texture2D", "res://icon.png");
+        assert_eq!(tex_id, tex_id_again, "same (type, path) must reuse the same id");
+
+        let other_id = builder.add_ext_resource("Texture2D", "res://other.png");
+        assert_ne!(tex_id, other_id);
+
+        let mat_id = builder.add_sub_resource("StandardMaterial3D", "albedo_color = Color(1, 1, 1, 1)");
+        let mat_id_again = builder.add_sub_resource("StandardMaterial3D", "albedo_color = Color(1, 1, 1, 1)");
+        assert_eq!(mat_id, mat_id_again, "same (type, body) must reuse the same id");
+
+        // 2 distinct ext resources + 1 sub resource + the scene's own slot.
+        assert_eq!(builder.load_steps(), 4);
+    }
+
+    #[test]
+    fn test_scene_builder_build_emits_header_tables_and_nodes_in_order() {
+        let mut builder = SceneBuilder::new();
+        let tex_id = builder.add_ext_resource("Texture2D", "res://icon.png");
+        let mat_id = builder.add_sub_resource("StandardMaterial3D", "albedo_color = Color(1, 1, 1, 1)");
+        builder.add_node(format!(
+            "[node name=\"Icon\" type=\"Sprite2D\"]\ntexture = ExtResource(\"{}\")\nmaterial = SubResource(\"{}\")\n",
+            tex_id, mat_id
+        ));
+
+        let output = builder.build();
+        let header_pos = output.find("[gd_scene load_steps=3 format=3 uid=\"uid://").expect("missing gd_scene header");
+        let ext_pos = output.find(&format!("[ext_resource type=\"Texture2D\" path=\"res://icon.png\" id=\"{}\"]", tex_id)).expect("missing ext_resource line");
+        let sub_pos = output.find(&format!("[sub_resource type=\"StandardMaterial3D\" id=\"{}\"]", mat_id)).expect("missing sub_resource line");
+        let node_pos = output.find("[node name=\"Icon\" type=\"Sprite2D\"]").expect("missing node block");
+
+        assert!(header_pos < ext_pos, "header must come before the resource tables");
+        assert!(ext_pos < sub_pos, "ext_resources must come before sub_resources");
+        assert!(sub_pos < node_pos, "resource tables must come before the node tree");
+    }
+
+    #[test]
+    fn test_scene_builder_with_uid_overrides_the_placeholder() {
+        let builder = SceneBuilder::new().with_uid("uid://abc123");
+        let output = builder.build();
+        assert!(output.starts_with("[gd_scene load_steps=1 format=3 uid=\"uid://abc123\"]\n\n"));
+    }
+
+    #[test]
+    fn test_tokenize_tscn_splits_headers_and_properties() {
+        let tscn = r#"[node name="Player" type="Node2D"]
+position = Vector2(1, 2)
+"#;
+        let lines = tokenize_tscn(tscn);
+        assert_eq!(lines.len(), 2);
+
+        match &lines[0] {
+            TscnLine::Header { tag, attrs } => {
+                assert_eq!(tag, "node");
+                assert_eq!(attrs.get("name"), Some(&"Player".to_string()));
+                assert_eq!(attrs.get("type"), Some(&"Node2D".to_string()));
+            }
+            other => panic!("expected a Header line, got {:?}", other),
+        }
+        match &lines[1] {
+            TscnLine::Property { key, value } => {
+                assert_eq!(key, "position");
+                assert_eq!(value, "Vector2(1, 2)");
+            }
+            other => panic!("expected a Property line, got {:?}", other),
+        }
+    }
 }
\ No newline at end of file
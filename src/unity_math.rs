@@ -0,0 +1,464 @@
+//! Unity -> Godot conversions for the common math types.
+//!
+//! Unity is left-handed, Y-up; Godot is right-handed, Y-up. A straight
+//! field-for-field copy therefore mirrors the scene along Z. These types
+//! apply that flip in `to_godot` so callers can copy `m_LocalPosition`,
+//! `m_LocalRotation`, etc. straight out of Unity YAML.
+
+use crate::translator::UnityValue;
+use crate::yaml::{Yaml, YamlMap};
+use rust_yaml_derive::UnityValue;
+
+/// Parse a `Tag(a, b, c, ...)` Godot literal into its comma-separated
+/// float arguments, e.g. `"Vector3(1, 2, -3)"` -> `[1.0, 2.0, -3.0]`.
+/// Shared by every `UnityValue::from_godot` impl in this file so each one
+/// only has to know its own tag and field count.
+fn parse_godot_floats(tscn: &str, tag: &str) -> Option<Vec<f64>> {
+    let inner = tscn.trim().strip_prefix(tag)?.strip_prefix('(')?.strip_suffix(')')?;
+    inner.split(',').map(|part| part.trim().parse().ok()).collect()
+}
+
+/// Which axes a 2D value (currently just `UnityVector2`) should flip.
+/// Texture/UV coordinates need a Y-flip going from Unity to Godot; plain 2D
+/// positions (e.g. a `RectTransform` anchor) don't.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AxisConvention {
+    #[default]
+    Position,
+    TextureUv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnityVector3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl UnityValue for UnityVector3 {
+    fn to_godot(&self) -> String {
+        // Unity -> Godot: negate Z to flip from left-handed to right-handed.
+        format!("Vector3({}, {}, {})", self.x, self.y, -self.z)
+    }
+
+    fn from_yaml(yaml: &Yaml) -> Option<Self> {
+        let map = match yaml {
+            Yaml::Hash(map) => map,
+            _ => return None,
+        };
+        Some(UnityVector3 {
+            x: map.get("x")?.as_f64()?,
+            y: map.get("y")?.as_f64()?,
+            z: map.get("z")?.as_f64()?,
+        })
+    }
+
+    fn from_godot(tscn: &str) -> Option<Self> {
+        let floats = parse_godot_floats(tscn, "Vector3")?;
+        // Inverse of to_godot: undo the Z flip.
+        Some(UnityVector3 { x: *floats.first()?, y: *floats.get(1)?, z: -*floats.get(2)? })
+    }
+
+    fn to_yaml(&self) -> Yaml {
+        let mut map = YamlMap::new();
+        map.insert("x".to_string(), Yaml::Real(self.x.to_string()));
+        map.insert("y".to_string(), Yaml::Real(self.y.to_string()));
+        map.insert("z".to_string(), Yaml::Real(self.z.to_string()));
+        Yaml::Hash(map)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnityVector2 {
+    pub x: f64,
+    pub y: f64,
+    pub convention: AxisConvention,
+}
+
+impl UnityValue for UnityVector2 {
+    fn to_godot(&self) -> String {
+        let y = match self.convention {
+            AxisConvention::TextureUv => 1.0 - self.y,
+            AxisConvention::Position => self.y,
+        };
+        format!("Vector2({}, {})", self.x, y)
+    }
+
+    fn from_yaml(yaml: &Yaml) -> Option<Self> {
+        let map = match yaml {
+            Yaml::Hash(map) => map,
+            _ => return None,
+        };
+        Some(UnityVector2 {
+            x: map.get("x")?.as_f64()?,
+            y: map.get("y")?.as_f64()?,
+            convention: AxisConvention::default(),
+        })
+    }
+
+    /// `to_godot`'s Y value depends on `convention`, which isn't recoverable
+    /// from the rendered literal alone, so this assumes the default
+    /// `Position` convention (no flip) — round-tripping a `TextureUv` value
+    /// through this will not recover the original `y`.
+    fn from_godot(tscn: &str) -> Option<Self> {
+        let floats = parse_godot_floats(tscn, "Vector2")?;
+        Some(UnityVector2 {
+            x: *floats.first()?,
+            y: *floats.get(1)?,
+            convention: AxisConvention::Position,
+        })
+    }
+
+    fn to_yaml(&self) -> Yaml {
+        let mut map = YamlMap::new();
+        map.insert("x".to_string(), Yaml::Real(self.x.to_string()));
+        map.insert("y".to_string(), Yaml::Real(self.y.to_string()));
+        Yaml::Hash(map)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnityQuaternion {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl UnityQuaternion {
+    /// Apply the handedness flip (negate Z and W) and renormalize.
+    fn to_godot_quaternion(self) -> (f64, f64, f64, f64) {
+        let (x, y, z, w) = (self.x, self.y, -self.z, -self.w);
+        let len = (x * x + y * y + z * z + w * w).sqrt();
+        if len == 0.0 {
+            (x, y, z, w)
+        } else {
+            (x / len, y / len, z / len, w / len)
+        }
+    }
+}
+
+impl UnityValue for UnityQuaternion {
+    fn to_godot(&self) -> String {
+        let (x, y, z, w) = self.to_godot_quaternion();
+        format!("Quaternion({}, {}, {}, {})", x, y, z, w)
+    }
+
+    fn from_yaml(yaml: &Yaml) -> Option<Self> {
+        let map = match yaml {
+            Yaml::Hash(map) => map,
+            _ => return None,
+        };
+        Some(UnityQuaternion {
+            x: map.get("x")?.as_f64()?,
+            y: map.get("y")?.as_f64()?,
+            z: map.get("z")?.as_f64()?,
+            w: map.get("w")?.as_f64()?,
+        })
+    }
+
+    /// Inverse of `to_godot_quaternion`: undo the z/w flip. Rotation
+    /// quaternions are unit-length in practice, so the renormalization
+    /// `to_godot_quaternion` applies is a no-op here and this recovers the
+    /// original components exactly.
+    fn from_godot(tscn: &str) -> Option<Self> {
+        let floats = parse_godot_floats(tscn, "Quaternion")?;
+        Some(UnityQuaternion {
+            x: *floats.first()?,
+            y: *floats.get(1)?,
+            z: -*floats.get(2)?,
+            w: -*floats.get(3)?,
+        })
+    }
+
+    fn to_yaml(&self) -> Yaml {
+        let mut map = YamlMap::new();
+        map.insert("x".to_string(), Yaml::Real(self.x.to_string()));
+        map.insert("y".to_string(), Yaml::Real(self.y.to_string()));
+        map.insert("z".to_string(), Yaml::Real(self.z.to_string()));
+        map.insert("w".to_string(), Yaml::Real(self.w.to_string()));
+        Yaml::Hash(map)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnityColor {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl UnityValue for UnityColor {
+    fn to_godot(&self) -> String {
+        // Linear RGBA in both engines, so this one is a straight copy.
+        format!("Color({}, {}, {}, {})", self.r, self.g, self.b, self.a)
+    }
+
+    fn from_yaml(yaml: &Yaml) -> Option<Self> {
+        let map = match yaml {
+            Yaml::Hash(map) => map,
+            _ => return None,
+        };
+        Some(UnityColor {
+            r: map.get("r")?.as_f64()?,
+            g: map.get("g")?.as_f64()?,
+            b: map.get("b")?.as_f64()?,
+            a: map.get("a")?.as_f64()?,
+        })
+    }
+
+    fn from_godot(tscn: &str) -> Option<Self> {
+        let floats = parse_godot_floats(tscn, "Color")?;
+        Some(UnityColor {
+            r: *floats.first()?,
+            g: *floats.get(1)?,
+            b: *floats.get(2)?,
+            a: *floats.get(3)?,
+        })
+    }
+
+    fn to_yaml(&self) -> Yaml {
+        let mut map = YamlMap::new();
+        map.insert("r".to_string(), Yaml::Real(self.r.to_string()));
+        map.insert("g".to_string(), Yaml::Real(self.g.to_string()));
+        map.insert("b".to_string(), Yaml::Real(self.b.to_string()));
+        map.insert("a".to_string(), Yaml::Real(self.a.to_string()));
+        Yaml::Hash(map)
+    }
+}
+
+/// A Unity `Transform` (local position/rotation/scale), converted into a
+/// single Godot `Transform3D(basis, origin)` literal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnityTransform {
+    pub position: UnityVector3,
+    pub rotation: UnityQuaternion,
+    pub scale: UnityVector3,
+}
+
+impl UnityValue for UnityTransform {
+    fn to_godot(&self) -> String {
+        let (x, y, z, w) = self.rotation.to_godot_quaternion();
+
+        // Quaternion -> rotation matrix, then scale each column, matching
+        // how Godot builds a Basis from rotation + scale.
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (w * x, w * y, w * z);
+
+        let col_x = (
+            (1.0 - 2.0 * (yy + zz)) * self.scale.x,
+            (2.0 * (xy + wz)) * self.scale.x,
+            (2.0 * (xz - wy)) * self.scale.x,
+        );
+        let col_y = (
+            (2.0 * (xy - wz)) * self.scale.y,
+            (1.0 - 2.0 * (xx + zz)) * self.scale.y,
+            (2.0 * (yz + wx)) * self.scale.y,
+        );
+        let col_z = (
+            (2.0 * (xz + wy)) * self.scale.z,
+            (2.0 * (yz - wx)) * self.scale.z,
+            (1.0 - 2.0 * (xx + yy)) * self.scale.z,
+        );
+
+        format!(
+            "Transform3D({}, {}, {}, {}, {}, {}, {}, {}, {}, {})",
+            col_x.0, col_x.1, col_x.2,
+            col_y.0, col_y.1, col_y.2,
+            col_z.0, col_z.1, col_z.2,
+            self.position.to_godot().trim_start_matches("Vector3").trim_start_matches('(').trim_end_matches(')'),
+        )
+    }
+
+    fn from_yaml(yaml: &Yaml) -> Option<Self> {
+        let map = match yaml {
+            Yaml::Hash(map) => map,
+            _ => return None,
+        };
+        Some(UnityTransform {
+            position: UnityVector3::from_yaml(map.get("m_LocalPosition")?)?,
+            rotation: UnityQuaternion::from_yaml(map.get("m_LocalRotation")?)?,
+            scale: UnityVector3::from_yaml(map.get("m_LocalScale")?)?,
+        })
+    }
+
+    // `from_godot` is left at its default (`None`): to_godot collapses
+    // position/rotation/scale into a single `Transform3D` basis, and
+    // recovering rotation + scale from that basis (matrix decomposition)
+    // is a meaningfully harder inverse than the other types here. Not
+    // needed yet, so it's left unimplemented rather than done halfway.
+
+    fn to_yaml(&self) -> Yaml {
+        let mut map = YamlMap::new();
+        map.insert("m_LocalPosition".to_string(), self.position.to_yaml());
+        map.insert("m_LocalRotation".to_string(), self.rotation.to_yaml());
+        map.insert("m_LocalScale".to_string(), self.scale.to_yaml());
+        Yaml::Hash(map)
+    }
+}
+
+/// A minimal named-point payload built with `#[derive(UnityValue)]` instead
+/// of a hand-written `impl`, exercising both the derive's plain-`String`
+/// field codegen and its nested-`UnityValue` field codegen in one struct.
+#[derive(Debug, Clone, PartialEq, UnityValue)]
+pub struct UnityNamedPoint {
+    #[unity(name = "m_Name")]
+    #[godot(name = "name")]
+    pub name: String,
+    #[unity(name = "m_Position")]
+    #[godot(name = "position")]
+    pub position: UnityVector3,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::yaml::Yaml;
+
+    /// Regression test for a real parser document (not a hand-built
+    /// `Yaml::Hash`): Unity writes most vector components as bare integers
+    /// (`x: 0`) rather than decimals, and `from_scalar` classifies those as
+    /// `Yaml::Integer`, not `Yaml::Real`/`Yaml::Value`. `from_yaml` must
+    /// still pick them up.
+    #[test]
+    fn test_vector3_from_yaml_accepts_integer_valued_components() {
+        let yaml_text = "x: 0\ny: 1.5\nz: 0\n";
+        let lines: Vec<&str> = yaml_text.lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+
+        let vector = UnityVector3::from_yaml(&doc).expect("from_yaml should accept integer components");
+        assert_eq!(vector, UnityVector3 { x: 0.0, y: 1.5, z: 0.0 });
+    }
+
+    #[test]
+    fn test_vector3_to_godot_flips_z() {
+        let lines: Vec<&str> = "x: 1\ny: 2\nz: 3\n".lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let vector = UnityVector3::from_yaml(&doc).expect("from_yaml failed");
+        assert_eq!(vector.to_godot(), "Vector3(1, 2, -3)");
+    }
+
+    #[test]
+    fn test_vector2_from_yaml_and_to_godot_with_default_position_convention() {
+        let lines: Vec<&str> = "x: 0\ny: 0.25\n".lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let vector = UnityVector2::from_yaml(&doc).expect("from_yaml should accept an integer x");
+        assert_eq!(vector.convention, AxisConvention::Position);
+        assert_eq!(vector.to_godot(), "Vector2(0, 0.25)");
+    }
+
+    #[test]
+    fn test_vector2_texture_uv_convention_flips_y() {
+        let uv = UnityVector2 { x: 0.5, y: 0.25, convention: AxisConvention::TextureUv };
+        assert_eq!(uv.to_godot(), "Vector2(0.5, 0.75)");
+    }
+
+    #[test]
+    fn test_quaternion_from_yaml_normalizes_and_flips_handedness() {
+        // A non-unit quaternion straight out of real YAML (bare integers
+        // included): to_godot must flip z/w for handedness and renormalize.
+        let lines: Vec<&str> = "x: 0\ny: 0\nz: 0\nw: 2\n".lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let quat = UnityQuaternion::from_yaml(&doc).expect("from_yaml failed");
+        assert_eq!(quat, UnityQuaternion { x: 0.0, y: 0.0, z: 0.0, w: 2.0 });
+        assert_eq!(quat.to_godot(), "Quaternion(0, 0, -0, -1)");
+    }
+
+    #[test]
+    fn test_color_from_yaml_is_a_straight_copy() {
+        let lines: Vec<&str> = "r: 1\ng: 0\nb: 0.5\na: 1\n".lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let color = UnityColor::from_yaml(&doc).expect("from_yaml should accept integer channels");
+        assert_eq!(color, UnityColor { r: 1.0, g: 0.0, b: 0.5, a: 1.0 });
+        assert_eq!(color.to_godot(), "Color(1, 0, 0.5, 1)");
+    }
+
+    #[test]
+    fn test_transform_from_yaml_parses_nested_vectors_and_builds_basis() {
+        let yaml_text = r#"m_LocalPosition: {x: 1, y: 2, z: 3}
+m_LocalRotation: {x: 0, y: 0, z: 0, w: 1}
+m_LocalScale: {x: 1, y: 1, z: 1}
+"#;
+        let lines: Vec<&str> = yaml_text.lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let transform = UnityTransform::from_yaml(&doc).expect("from_yaml failed");
+
+        assert_eq!(transform.position, UnityVector3 { x: 1.0, y: 2.0, z: 3.0 });
+        // Identity rotation + unit scale: the basis is the identity matrix,
+        // with the (already Z-flipped) position as the origin.
+        assert_eq!(
+            transform.to_godot(),
+            "Transform3D(1, 0, 0, 0, 1, -0, -0, 0, 1, 1, 2, -3)"
+        );
+    }
+
+    #[test]
+    fn test_vector3_round_trips_through_godot_and_yaml() {
+        let lines: Vec<&str> = "x: 1\ny: 2\nz: 3\n".lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let original = UnityVector3::from_yaml(&doc).expect("from_yaml failed");
+
+        let via_godot = UnityVector3::from_godot(&original.to_godot()).expect("from_godot failed");
+        assert_eq!(via_godot, original);
+
+        let via_yaml = UnityVector3::from_yaml(&original.to_yaml()).expect("from_yaml(to_yaml()) failed");
+        assert_eq!(via_yaml, original);
+    }
+
+    #[test]
+    fn test_quaternion_round_trips_through_godot_and_yaml() {
+        // Already unit-length, so the renormalization `to_godot_quaternion`
+        // applies is an exact no-op and the round trip has no floating
+        // point drift to account for.
+        let lines: Vec<&str> = "x: 0\ny: 1\nz: 0\nw: 0\n".lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let original = UnityQuaternion::from_yaml(&doc).expect("from_yaml failed");
+
+        let via_godot = UnityQuaternion::from_godot(&original.to_godot()).expect("from_godot failed");
+        assert_eq!(via_godot, original);
+
+        let via_yaml = UnityQuaternion::from_yaml(&original.to_yaml()).expect("from_yaml(to_yaml()) failed");
+        assert_eq!(via_yaml, original);
+    }
+
+    #[test]
+    fn test_color_round_trips_through_godot_and_yaml() {
+        let lines: Vec<&str> = "r: 1\ng: 0\nb: 0.5\na: 1\n".lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let original = UnityColor::from_yaml(&doc).expect("from_yaml failed");
+
+        let via_godot = UnityColor::from_godot(&original.to_godot()).expect("from_godot failed");
+        assert_eq!(via_godot, original);
+
+        let via_yaml = UnityColor::from_yaml(&original.to_yaml()).expect("from_yaml(to_yaml()) failed");
+        assert_eq!(via_yaml, original);
+    }
+
+    #[test]
+    fn test_transform_to_yaml_round_trips_through_from_yaml() {
+        let yaml_text = r#"m_LocalPosition: {x: 1, y: 2, z: 3}
+m_LocalRotation: {x: 0, y: 0, z: 0, w: 1}
+m_LocalScale: {x: 1, y: 1, z: 1}
+"#;
+        let lines: Vec<&str> = yaml_text.lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let original = UnityTransform::from_yaml(&doc).expect("from_yaml failed");
+
+        let via_yaml = UnityTransform::from_yaml(&original.to_yaml()).expect("from_yaml(to_yaml()) failed");
+        assert_eq!(via_yaml, original);
+    }
+
+    #[test]
+    fn test_derived_unity_value_parses_string_and_nested_fields() {
+        let yaml_text = "m_Name: Spawn\nm_Position: {x: 1, y: 2, z: 3}\n";
+        let lines: Vec<&str> = yaml_text.lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let point = UnityNamedPoint::from_yaml(&doc).expect("derived from_yaml failed");
+
+        assert_eq!(point.name, "Spawn");
+        assert_eq!(point.position, UnityVector3 { x: 1.0, y: 2.0, z: 3.0 });
+        assert_eq!(point.to_godot(), "name = \"Spawn\"\nposition = Vector3(1, 2, -3)\n");
+    }
+}
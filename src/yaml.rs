@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use std::fmt;
-use crate::unity_types::{translate_yaml, TranslationResult};
+use crate::unity_types::translate_yaml;
 
 use std::fs::{self, OpenOptions};
 use std::io::Write;
@@ -8,11 +8,245 @@ use std::path::Path;
 
 const UNITY_TYPES_DIR: &str = "/home/med-sal/git_Projects/scenebridge-rs/src/unity_types";
 
+/// Insertion-ordered map backing `Yaml::Hash`. Unity is order-sensitive (e.g.
+/// `m_Name` is expected before `m_LocalPosition`) and a round-tripped
+/// scene/prefab must come back with the same field order it was parsed
+/// with, which `std::collections::HashMap` can't guarantee. This mirrors
+/// yaml-rust's `preserve_order` feature (a `LinkedHashMap` swapped in for
+/// `BTreeMap`), implemented here as a plain `Vec<(String, Yaml)>` so the
+/// crate doesn't need an extra dependency.
+#[derive(Debug, Clone, Default)]
+pub struct YamlMap {
+    entries: Vec<(String, Yaml)>,
+}
+
+impl YamlMap {
+    pub fn new() -> Self {
+        YamlMap { entries: Vec::new() }
+    }
+
+    /// Insert a key, keeping its original position if it already existed
+    /// (matching `HashMap::insert`'s "last value wins" semantics) or
+    /// appending it at the end if it's new.
+    pub fn insert(&mut self, key: String, value: Yaml) -> Option<Yaml> {
+        if let Some(slot) = self.entries.iter_mut().find(|(k, _)| *k == key) {
+            Some(std::mem::replace(&mut slot.1, value))
+        } else {
+            self.entries.push((key, value));
+            None
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Yaml> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.entries.iter().any(|(k, _)| k == key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &Yaml> {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Yaml)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl std::ops::Index<&str> for YamlMap {
+    type Output = Yaml;
+
+    fn index(&self, key: &str) -> &Yaml {
+        self.get(key)
+            .unwrap_or_else(|| panic!("no entry found for key '{}'", key))
+    }
+}
+
+impl IntoIterator for YamlMap {
+    type Item = (String, Yaml);
+    type IntoIter = std::vec::IntoIter<(String, Yaml)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a YamlMap {
+    type Item = (&'a String, &'a Yaml);
+    type IntoIter = std::iter::Map<
+        std::slice::Iter<'a, (String, Yaml)>,
+        fn(&'a (String, Yaml)) -> (&'a String, &'a Yaml),
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+}
+
+impl FromIterator<(String, Yaml)> for YamlMap {
+    fn from_iter<T: IntoIterator<Item = (String, Yaml)>>(iter: T) -> Self {
+        let mut map = YamlMap::new();
+        for (k, v) in iter {
+            map.insert(k, v);
+        }
+        map
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Yaml {
-    Value(String),             // just a raw string
-    Hash(HashMap<String, Yaml>), // key -> Yaml
+    Value(String),             // a raw string that didn't classify as anything below
+    Integer(i64),
+    Real(String),               // float, kept as the original text so no precision is lost
+    Boolean(bool),
+    Null,
+    Hash(YamlMap),             // key -> Yaml, insertion ordered
     Array(Vec<Yaml>),          // sequence of Yaml
+    Alias(String),              // `*name`, an unresolved reference to an anchored node
+    Anchor(String, Box<Yaml>), // `&name value`, a node labeled for later aliasing
+    /// Sentinel returned by `Index` when a key/index is missing or `self`
+    /// is the wrong kind of node, so chained lookups like
+    /// `doc["GameObject"]["m_Component"][0]["component"]` never panic.
+    BadValue,
+}
+
+/// Shared instance `Index` impls hand back on a miss, so indexing never
+/// needs to allocate or panic just to produce a sentinel.
+static BAD_VALUE: Yaml = Yaml::BadValue;
+
+impl Yaml {
+    /// Classify a raw scalar token following the YAML 1.2 core schema, the
+    /// way `parse_unity_object`/`parse_inline_mapping` need to:
+    ///
+    /// - a single- or double-quoted token always stays `Value` (its quotes
+    ///   stripped), regardless of what its contents look like;
+    /// - `null`/`~`/empty -> `Null`;
+    /// - `true`/`false` (case-insensitive) -> `Boolean`;
+    /// - a bare decimal, `0x` hex, or `0o` octal integer that fits in `i64`
+    ///   -> `Integer`;
+    /// - anything else parseable as a float, including `.inf`/`.nan` -> `Real`
+    ///   (keeping the original text so no precision is lost);
+    /// - otherwise `Value` (a plain string).
+    pub fn from_scalar(s: &str) -> Yaml {
+        if let Some(unquoted) = strip_matching_quotes(s) {
+            return Yaml::Value(unquoted.to_string());
+        }
+        if s.is_empty() || s == "~" || s.eq_ignore_ascii_case("null") {
+            return Yaml::Null;
+        }
+        if s.eq_ignore_ascii_case("true") {
+            return Yaml::Boolean(true);
+        }
+        if s.eq_ignore_ascii_case("false") {
+            return Yaml::Boolean(false);
+        }
+        if let Some(i) = parse_radix_int(s) {
+            return Yaml::Integer(i);
+        }
+        if let Ok(i) = s.parse::<i64>() {
+            return Yaml::Integer(i);
+        }
+        if is_core_schema_float(s) {
+            return Yaml::Real(s.to_string());
+        }
+        Yaml::Value(s.to_string())
+    }
+
+    /// `true` for any non-container variant (`Value`, `Integer`, `Real`,
+    /// `Boolean`, `Null`) — the things that get printed inline rather than
+    /// recursed into. An `Anchor` defers to the node it labels; an `Alias`
+    /// is always printed inline as `*name`.
+    pub fn is_scalar(&self) -> bool {
+        match self {
+            Yaml::Hash(_) | Yaml::Array(_) => false,
+            Yaml::Anchor(_, inner) => inner.is_scalar(),
+            _ => true,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Yaml::Integer(i) => Some(*i),
+            Yaml::Value(s) | Yaml::Real(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Yaml::Real(s) | Yaml::Value(s) => parse_core_schema_float(s),
+            Yaml::Integer(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Yaml::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Same family as `as_i64`/`as_f64`/`as_bool`: `None` for anything that
+    /// isn't a plain scalar string, including `BadValue`, so a missed chained
+    /// index (e.g. `doc["m_Modifications"][0]["objectReference"].as_str()`)
+    /// short-circuits to `None` instead of panicking.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Yaml::Value(s) | Yaml::Real(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Yaml::Null)
+    }
+
+    /// `true` for the `Index`-miss sentinel, mirroring yaml-rust's `BadValue`.
+    pub fn is_badvalue(&self) -> bool {
+        matches!(self, Yaml::BadValue)
+    }
+}
+
+impl std::ops::Index<&str> for Yaml {
+    type Output = Yaml;
+
+    /// Look up `key` in a `Hash`, or hand back `Yaml::BadValue` if `self`
+    /// isn't a `Hash` or doesn't have that key — never panics, so deep
+    /// chains like `doc["GameObject"]["m_Component"]` can be followed
+    /// without checking each step.
+    fn index(&self, key: &str) -> &Yaml {
+        match self {
+            Yaml::Hash(map) => map.get(key).unwrap_or(&BAD_VALUE),
+            _ => &BAD_VALUE,
+        }
+    }
+}
+
+impl std::ops::Index<usize> for Yaml {
+    type Output = Yaml;
+
+    /// Look up `index` in an `Array`, or hand back `Yaml::BadValue` if
+    /// `self` isn't an `Array` or the index is out of bounds.
+    fn index(&self, index: usize) -> &Yaml {
+        match self {
+            Yaml::Array(arr) => arr.get(index).unwrap_or(&BAD_VALUE),
+            _ => &BAD_VALUE,
+        }
+    }
 }
 
 impl fmt::Display for Yaml {
@@ -34,14 +268,100 @@ impl Yaml {
     pub fn load_from_file<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
         let text = fs::read_to_string(path)?;
         let lines: Vec<&str> = text.lines().collect();
-        // Assuming you have a `parse_yaml` function returning Yaml
-        Ok(Self::parse_unity_object(&lines))
+        Self::parse_unity_object(&lines)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+
+    /// Like `load_from_file`, but runs in strict mode: leading `#` comments
+    /// are pulled out instead of being silently dropped by the block parser,
+    /// and any scalar that's easy to misparse (`on`/`off`, a dotted number
+    /// with a trailing zero such as `1.10`) is flagged rather than silently
+    /// coerced. Returns the parsed tree alongside a `ConversionReport` so a
+    /// large scene import can be audited instead of trusted blindly.
+    pub fn load_from_file_strict<P: AsRef<Path>>(
+        path: P,
+    ) -> std::io::Result<(Yaml, ConversionReport)> {
+        let text = fs::read_to_string(path)?;
+        let lines: Vec<&str> = text.lines().collect();
+        Self::parse_unity_object_strict(&lines)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+
+    /// Strict-mode counterpart to `parse_unity_object`: see `load_from_file_strict`.
+    ///
+    /// A scalar `is_ambiguous_scalar` flags (`on`/`off`/`yes`/`no`, or a
+    /// dotted number with a trailing zero like `1.10`) is quoted before
+    /// being handed to `parse_unity_object`, so it actually comes back as a
+    /// `Yaml::Value` string — matching the warning recorded for it. (On the
+    /// unflagged path `1.10` comes back as a `Real`; `on`/`off`/`yes`/`no`
+    /// are plain `Value` strings on either path.)
+    ///
+    /// Leading `#` comments (those before the first real line) are pulled
+    /// into `report.leading_comments` verbatim instead of being parsed, so
+    /// `ConversionReport::prepend_leading_comments` can carry them into
+    /// re-emitted output. A comment after the document has started is left
+    /// in place and handed to `parse_unity_object` like any other line, which
+    /// skips it (or reads it as a key if it contains a `:`); this parser has
+    /// no per-field attachment point to carry it on.
+    pub fn parse_unity_object_strict(lines: &[&str]) -> Result<(Yaml, ConversionReport), ParseError> {
+        let mut report = ConversionReport::new();
+        let mut kept_lines: Vec<String> = Vec::new();
+        let mut still_leading = true;
+
+        for line in lines {
+            let trimmed = line.trim();
+            if trimmed.starts_with('#') {
+                if still_leading {
+                    report.leading_comments.push((*line).to_string());
+                    continue;
+                }
+            } else if !trimmed.is_empty() {
+                still_leading = false;
+            }
+            if let Some(idx) = trimmed.find(':') {
+                let key = trimmed[..idx].trim();
+                let value = trimmed[idx + 1..].trim();
+                if !value.is_empty() && is_ambiguous_scalar(value) {
+                    report.warn_ambiguous_scalar(key, value);
+                    let indent = &line[..line.len() - line.trim_start().len()];
+                    kept_lines.push(format!("{}{}: \"{}\"", indent, key, value));
+                    continue;
+                }
+            }
+            kept_lines.push((*line).to_string());
+        }
+
+        let borrowed_lines: Vec<&str> = kept_lines.iter().map(String::as_str).collect();
+        let yaml = Self::parse_unity_object(&borrowed_lines)?;
+        Ok((yaml, report))
+    }
+
+    /// A second, stricter strict mode, modeled on StrictYAML rather than on
+    /// `parse_unity_object_strict`'s "flag and keep going" reports: every
+    /// scalar comes back as a plain `Yaml::Value` string (no int/bool/null
+    /// coercion), and flow-style `{...}`/`[...]`, anchors/aliases, tags, and
+    /// duplicate mapping keys are all hard parse errors instead of silently
+    /// accepted or merely flagged. Meant for configuration files where
+    /// YAML's type-coercion footguns are a liability rather than a
+    /// convenience; the returned tree still works with `get_str` and friends.
+    pub fn load_strict_from_str(text: &str) -> Result<Yaml, ParseError> {
+        let lines: Vec<&str> = text.lines().collect();
+        Ok(parse_block_strict(&lines, 0, 1)?.0)
     }
 
     // determine if it can be written inline
     pub fn is_flat_hash(&self) -> bool {
         match self {
-            Yaml::Hash(map) => map.values().all(|v| matches!(v, Yaml::Value(_))),
+            Yaml::Hash(map) => map.values().all(|v| v.is_scalar()),
+            _ => false,
+        }
+    }
+
+    /// The sequence analogue of `is_flat_hash`: an `Array` whose elements
+    /// are all scalars, and so can be written `[a, b, c]` on one line.
+    pub fn is_flat_array(&self) -> bool {
+        match self {
+            Yaml::Array(items) => items.iter().all(|v| v.is_scalar()),
             _ => false,
         }
     }
@@ -52,14 +372,16 @@ impl Yaml {
         match self {
             Yaml::Hash(map) => {
                 for (_key, value) in map {
+                    if value.is_scalar() {
+                        continue;
+                    }
                     match value {
-                        Yaml::Value(_) => continue,
                         Yaml::Hash(_) => {
                             if translate_yaml(value).is_err() {
                                 return false;
                             }
                         }
-                        Yaml::Array(_) => return false,
+                        _ => return false,
                     }
                 }
                 true
@@ -87,7 +409,7 @@ impl Yaml {
                                     godot_str
                                 ));
                             }
-                            Err(err) => {
+                            Err(_err) => {
                                 // ❌ Failure — show nested YAML formatted
                                 panic!( "failed to translate this yaml:\n{}",value.to_chatty_helper(key));
                             }
@@ -174,16 +496,27 @@ impl Yaml {
         out.push_str("        };\n\n");
         out.push_str(&format!("        Some({} {{\n", struct_name));
         for (fname, ftype, key) in &fields {
-            if ftype == "String" {
-                out.push_str(&format!(
+            match ftype.as_str() {
+                "String" => out.push_str(&format!(
                     "            {}: map.get(\"{}\")?.as_value_string()?,\n",
                     fname, key
-                ));
-            } else {
-                out.push_str(&format!(
+                )),
+                "i64" => out.push_str(&format!(
+                    "            {}: map.get(\"{}\")?.as_i64()?,\n",
+                    fname, key
+                )),
+                "f64" => out.push_str(&format!(
+                    "            {}: map.get(\"{}\")?.as_f64()?,\n",
+                    fname, key
+                )),
+                "bool" => out.push_str(&format!(
+                    "            {}: map.get(\"{}\")?.as_bool()?,\n",
+                    fname, key
+                )),
+                _ => out.push_str(&format!(
                     "            {}: {}::from_yaml(map.get(\"{}\")?)?,\n",
                     fname, ftype, key
-                ));
+                )),
             }
         }
         out.push_str("        })\n    }\n}\n");
@@ -222,9 +555,16 @@ impl Yaml {
     fn guess_field_type(yaml: &Yaml) -> String {
         match yaml {
             Yaml::Value(_) => "String".to_string(),
+            Yaml::Integer(_) => "i64".to_string(),
+            Yaml::Real(_) => "f64".to_string(),
+            Yaml::Boolean(_) => "bool".to_string(),
+            Yaml::Null => "Option<String>".to_string(),
             Yaml::Hash(map) if map.is_empty() => "UnityEmpty".to_string(),
             Yaml::Hash(_) => "UnityData".to_string(),
             Yaml::Array(_) => "Vec<String>".to_string(),
+            Yaml::Anchor(_, inner) => Self::guess_field_type(inner),
+            Yaml::Alias(_) => "String".to_string(),
+            Yaml::BadValue => "Option<String>".to_string(),
         }
     }
 
@@ -236,7 +576,7 @@ impl Yaml {
     ///     b: 0.4
     ///     a: 1
     pub fn to_indented_string(&self, key:&str ) -> String{
-        let mut tmp = HashMap::<String, Yaml>::new();
+        let mut tmp = YamlMap::new();
         tmp.insert(key.to_string(), self.clone() );
         format!("{}", Yaml::Hash(tmp))
     }
@@ -245,8 +585,8 @@ impl Yaml {
     /// Build a Chatty-style summary: shows which sub-hashes translated successfully.
     /// Each key maps either to the detected class name or to an "untranslated" placeholder.
     pub fn to_chatty_helper(&self, key: &str) -> String {
-        let mut outer = HashMap::<String, Yaml>::new();
-        let mut inner = HashMap::<String, Yaml>::new();
+        let mut outer = YamlMap::new();
+        let mut inner = YamlMap::new();
 
         if let Yaml::Hash(map) = self {
             for (sub_key, value) in map {
@@ -265,19 +605,18 @@ impl Yaml {
     }
 
     pub fn get_val(&self) -> Option<&str> {
-        if let Yaml::Value(s) = self {
-            Some(s)
-        } else {
-            None
+        match self {
+            Yaml::Value(s) | Yaml::Real(s) => Some(s),
+            _ => None,
         }
     }
-    
+
 
     /// Convenience helper: get a string field from a Yaml::Hash.
     pub fn get_str(&self, key: &str) -> Option<&str> {
         if let Yaml::Hash(map) = self {
             match map.get(key)? {
-                Yaml::Value(v) => Some(v),
+                Yaml::Value(v) | Yaml::Real(v) => Some(v),
                 _ => None,
             }
         } else {
@@ -289,11 +628,35 @@ impl Yaml {
         let indent_str = "  ".repeat(indent);
         match self {
             Yaml::Value(v) => write!(f, "{}", v),
+            Yaml::Integer(i) => write!(f, "{}", i),
+            Yaml::Real(s) => write!(f, "{}", s),
+            Yaml::Boolean(b) => write!(f, "{}", b),
+            Yaml::Null => write!(f, "null"),
+            // BadValue is a lookup sentinel, never something actually parsed
+            // or meant to be written out; print it the same as Null so a
+            // stray one in a saved file is at least harmless.
+            Yaml::BadValue => write!(f, "null"),
+            Yaml::Alias(name) => write!(f, "*{}", name),
+            Yaml::Anchor(name, inner) => {
+                if inner.is_scalar() {
+                    write!(f, "&{} {}", name, inner)
+                } else {
+                    writeln!(f, "&{}", name)?;
+                    inner.fmt_with_indent(f, indent + 1)
+                }
+            }
             Yaml::Hash(h) => {
                 for (k, v) in h {
                     match v {
-                        Yaml::Value(_) => writeln!(f, "{}{}: {}", indent_str, k, v)?,
-                        Yaml::Hash(_) | Yaml::Array(_) => {
+                        Yaml::Anchor(name, inner) if inner.is_scalar() => {
+                            writeln!(f, "{}{}: &{} {}", indent_str, k, name, inner)?;
+                        }
+                        Yaml::Anchor(name, inner) => {
+                            writeln!(f, "{}{}: &{}", indent_str, k, name)?;
+                            inner.fmt_with_indent(f, indent + 1)?;
+                        }
+                        _ if v.is_scalar() => writeln!(f, "{}{}: {}", indent_str, k, v)?,
+                        _ => {
                             writeln!(f, "{}{}:", indent_str, k)?;
                             v.fmt_with_indent(f, indent + 1)?;
                         }
@@ -303,10 +666,19 @@ impl Yaml {
             }
             Yaml::Array(a) => {
                 for item in a {
-                    write!(f, "{}- ", indent_str)?;
                     match item {
-                        Yaml::Value(_) => writeln!(f, "{}", item)?,
-                        Yaml::Hash(_) | Yaml::Array(_) => {
+                        Yaml::Anchor(name, inner) if inner.is_scalar() => {
+                            writeln!(f, "{}- &{} {}", indent_str, name, inner)?;
+                        }
+                        Yaml::Anchor(name, inner) => {
+                            writeln!(f, "{}- &{}", indent_str, name)?;
+                            inner.fmt_with_indent(f, indent + 1)?;
+                        }
+                        _ if item.is_scalar() => {
+                            writeln!(f, "{}- {}", indent_str, item)?;
+                        }
+                        _ => {
+                            write!(f, "{}- ", indent_str)?;
                             writeln!(f)?;
                             item.fmt_with_indent(f, indent + 1)?;
                         }
@@ -317,211 +689,981 @@ impl Yaml {
         }
     }
 
-    pub fn parse_unity_object(lines: &[&str]) -> Yaml {
-        fn parse_block(lines: &[&str], start_indent: usize) -> (Yaml, usize) {
-            let mut map: HashMap<String, Yaml> = HashMap::new();
-            let mut arr: Vec<Yaml> = Vec::new();
-            let mut is_array = false;
-            let mut i = 0;
+    /// Parse a Unity YAML block, surfacing `ParseError` instead of panicking
+    /// on the structures this parser doesn't (yet) support.
+    pub fn parse_unity_object(lines: &[&str]) -> Result<Yaml, ParseError> {
+        Ok(parse_block(lines, 0, 1)?.0)
+    }
 
-            #[cfg(debug_assertions)]
-            println!(
-                "\n🧩 ENTERING block (indent {}) with {} lines.",
-                start_indent,
-                lines.len(),
-            );
-
-            while i < lines.len() {
-                let line = lines[i];
-                let indent = line.chars().take_while(|c| *c == ' ').count();
-
-                // Block termination condition
-                if indent < start_indent {
-                    #[cfg(debug_assertions)]
-                    println!("↩️  Exiting block at line {} (indent {} < start_indent {})", i, indent, start_indent);
-                    break;
-                }
+    /// `panic!`s with the `ParseError`'s `Display` message, for callers that
+    /// haven't moved to the `Result`-returning API yet.
+    pub fn parse_unity_object_or_panic(lines: &[&str]) -> Yaml {
+        match Self::parse_unity_object(lines) {
+            Ok(yaml) => yaml,
+            Err(err) => panic!("{}", err),
+        }
+    }
 
-                let trimmed = line.trim();
+    /// Walk the tree in document order and substitute each `Yaml::Alias`
+    /// with a clone of the node recorded under the matching `Yaml::Anchor`.
+    /// The unresolved form (with `Anchor`/`Alias` nodes intact) is left
+    /// untouched by this method, so `save_to_file` can still re-emit the
+    /// original anchors losslessly — call `resolve_aliases` only on a copy
+    /// meant for consumption.
+    ///
+    /// An anchor is only visible to aliases that come *after* it, matching
+    /// real YAML's stream semantics: redefining `&name` later in the
+    /// document shadows the earlier one for any alias that follows, but an
+    /// alias referencing a `&name` that hasn't appeared yet is an error —
+    /// which also makes a directly self-referential anchor (`&a: {b: *a}`)
+    /// a forward reference and so rejected the same way, without ever
+    /// needing to expand a node into itself.
+    ///
+    /// Returns a `ParseError` (position `0:0`, since this runs after parsing
+    /// has already assigned real positions) for either case.
+    pub fn resolve_aliases(&self) -> Result<Yaml, ParseError> {
+        let mut anchors: HashMap<String, Yaml> = HashMap::new();
+        let mut resolving: Vec<String> = Vec::new();
+        resolve_aliases_in(self, &mut anchors, &mut resolving)
+    }
+}
 
-                if trimmed.is_empty() {
-                    #[cfg(debug_assertions)]
-                    println!("🪶 Skipping empty line {}", i);
-                    i += 1;
-                    continue;
-                }
+/// An error produced while parsing a Unity YAML block: the (1-indexed) line
+/// and column of the offending text, plus a human-readable message. Modeled
+/// on yaml-rust's `ScanError`/`Marker`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
 
-                // --- ARRAY ELEMENT DETECTED ---
-                if trimmed.starts_with('-') {
-                    is_array = true;
-                    let val_str = trimmed[1..].trim();
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "parse error at line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
 
-                    #[cfg(debug_assertions)]
-                    println!("📜 Line {} (indent {}): ARRAY element '{}'", i, indent, val_str);
+impl std::error::Error for ParseError {}
+
+/// Rebuild `node` with every `Yaml::Alias` replaced by a clone of its
+/// anchored node, walking the tree in document order and recording each
+/// `Yaml::Anchor` into `anchors` only *after* its own contents are resolved.
+/// That ordering is what makes a later `&name` shadow an earlier one (last
+/// definition wins) while an alias that comes before its anchor's first
+/// appearance fails instead of silently seeing a not-yet-defined node.
+///
+/// `resolving` tracks the alias names on the current resolution path as a
+/// defense-in-depth cycle guard: because every anchor is only inserted once
+/// fully resolved (alias-free), a stored anchor can never itself contain an
+/// alias back to a resolution still in progress, so in practice this path
+/// should be unreachable — but it costs little to keep as a backstop against
+/// recursing forever if that invariant is ever loosened.
+fn resolve_aliases_in(
+    node: &Yaml,
+    anchors: &mut HashMap<String, Yaml>,
+    resolving: &mut Vec<String>,
+) -> Result<Yaml, ParseError> {
+    match node {
+        Yaml::Alias(name) => {
+            if resolving.contains(name) {
+                return Err(ParseError {
+                    line: 0,
+                    column: 0,
+                    message: format!("cyclic alias reference: *{}", name),
+                });
+            }
+            let target = anchors.get(name).cloned().ok_or_else(|| ParseError {
+                line: 0,
+                column: 0,
+                message: format!("alias '*{}' references an anchor that hasn't been defined yet", name),
+            })?;
+            resolving.push(name.clone());
+            let resolved = resolve_aliases_in(&target, anchors, resolving)?;
+            resolving.pop();
+            Ok(resolved)
+        }
+        Yaml::Anchor(name, inner) => {
+            let resolved = resolve_aliases_in(inner, anchors, resolving)?;
+            anchors.insert(name.clone(), resolved.clone());
+            Ok(resolved)
+        }
+        Yaml::Hash(map) => {
+            let mut out = YamlMap::new();
+            for (k, v) in map {
+                out.insert(k.clone(), resolve_aliases_in(v, anchors, resolving)?);
+            }
+            Ok(Yaml::Hash(out))
+        }
+        Yaml::Array(arr) => {
+            let mut out = Vec::with_capacity(arr.len());
+            for v in arr {
+                out.push(resolve_aliases_in(v, anchors, resolving)?);
+            }
+            Ok(Yaml::Array(out))
+        }
+        other => Ok(other.clone()),
+    }
+}
 
-                    // Case 1: "-" followed by nested block
-                    if val_str.is_empty() {
-                        //#[cfg(debug_assertions)]
-                        panic!("  ↳ Array element with nested block below (indent > {})", indent);
+/// Parse a block of Unity YAML lines starting at `start_indent`. `base_line`
+/// is the 1-indexed line number of `lines[0]` in the original input, so
+/// errors raised while recursing into a nested block still point at the
+/// right place in the source file. Returns the parsed node plus how many of
+/// `lines` it consumed.
+fn parse_block(lines: &[&str], start_indent: usize, base_line: usize) -> Result<(Yaml, usize), ParseError> {
+    let mut map: YamlMap = YamlMap::new();
+    let mut arr: Vec<Yaml> = Vec::new();
+    let mut is_array = false;
+    let mut i = 0;
+
+    #[cfg(debug_assertions)]
+    println!(
+        "\n🧩 ENTERING block (indent {}) with {} lines.",
+        start_indent,
+        lines.len(),
+    );
+
+    while i < lines.len() {
+        let line = lines[i];
+        let indent = line.chars().take_while(|c| *c == ' ').count();
+
+        // Block termination condition
+        if indent < start_indent {
+            #[cfg(debug_assertions)]
+            println!("↩️  Exiting block at line {} (indent {} < start_indent {})", i, indent, start_indent);
+            break;
+        }
 
-                        let (child, consumed) = parse_block(&lines[i + 1..], indent);
-                        arr.push(child);
-                        i += consumed + 1;
-                        continue;
-                    }
+        let trimmed = line.trim();
 
-                    // Case 2: Inline array element with "key: value"
-                    if let Some(idx) = val_str.find(':') {
-                        let key = val_str[..idx].trim().to_string();
-                        let value = val_str[idx + 1..].trim();
-                        let mut child_map: HashMap<String, Yaml> = HashMap::new();
+        if trimmed.is_empty() {
+            #[cfg(debug_assertions)]
+            println!("🪶 Skipping empty line {}", i);
+            i += 1;
+            continue;
+        }
 
-                        #[cfg(debug_assertions)]
-                        println!("  ↳ Inline key/value: {} : {}", key, value);
+        // --- ARRAY ELEMENT DETECTED ---
+        if let Some(stripped) = trimmed.strip_prefix('-') {
+            is_array = true;
+            let val_str = stripped.trim();
 
-                        if value.starts_with('{') && value.ends_with('}') {
-                            child_map.insert(key, parse_inline_mapping(value));
-                        } else {
-                            child_map.insert(key, Yaml::Value(value.to_string()));
-                        }
+            #[cfg(debug_assertions)]
+            println!("📜 Line {} (indent {}): ARRAY element '{}'", i, indent, val_str);
+
+            // Case 1: "-" followed by nested block. Not yet supported: a bare
+            // "-" with the object's fields on subsequent lines.
+            if val_str.is_empty() {
+                return Err(ParseError {
+                    line: base_line + i,
+                    column: indent + 1,
+                    message: format!(
+                        "array element with nested block below is not yet supported: '{}'",
+                        line
+                    ),
+                });
+            }
 
-                        // Check next lines for nested fields under same array element
-                        if i + 1 < lines.len() {
-                            let next_indent = lines[i + 1].chars().take_while(|c| *c == ' ').count();
-                            if next_indent > indent {
-                                #[cfg(debug_assertions)]
-                                println!(
-                                    "  ↳ Parsing nested block for array element (indent {} -> {})",
-                                    indent, next_indent
-                                );
-                                let (nested, consumed) = parse_block(&lines[i + 1..], indent + 2);
-
-                                if let Yaml::Hash(nmap) = nested {
-                                    #[cfg(debug_assertions)]
-                                    println!("    ↳ Merging nested keys into array element: {:?}", nmap.keys());
-                                    for (k, v) in nmap {
-                                        child_map.insert(k, v);
-                                    }
-                                } else {
-                                    panic!(
-                                        "❌ Unexpected YAML structure in array element at line {} (partial {:?}). Problem line:\n'{}'",
-                                        i + consumed + 1,
-                                        child_map,
-                                        lines.get(i + consumed + 1).unwrap_or(&"<EOF>")
-                                    );
-                                }
-                                i += consumed + 1;
-                            } else {
-                                i += 1;
+            // Case 2: Inline array element with "key: value"
+            if let Some(idx) = val_str.find(':') {
+                let key = val_str[..idx].trim().to_string();
+                let value = val_str[idx + 1..].trim();
+                let mut child_map: YamlMap = YamlMap::new();
+
+                #[cfg(debug_assertions)]
+                println!("  ↳ Inline key/value: {} : {}", key, value);
+
+                if value.starts_with('{') && value.ends_with('}') {
+                    child_map.insert(key, parse_inline_mapping(value));
+                } else {
+                    child_map.insert(key, Yaml::from_scalar(value));
+                }
+
+                // Check next lines for nested fields under same array element
+                if i + 1 < lines.len() {
+                    let next_indent = lines[i + 1].chars().take_while(|c| *c == ' ').count();
+                    if next_indent > indent {
+                        #[cfg(debug_assertions)]
+                        println!(
+                            "  ↳ Parsing nested block for array element (indent {} -> {})",
+                            indent, next_indent
+                        );
+                        let (nested, consumed) =
+                            parse_block(&lines[i + 1..], indent + 2, base_line + i + 1)?;
+
+                        if let Yaml::Hash(nmap) = nested {
+                            #[cfg(debug_assertions)]
+                            println!("    ↳ Merging nested keys into array element: {:?}", nmap.keys().collect::<Vec<_>>());
+                            for (k, v) in nmap {
+                                child_map.insert(k, v);
                             }
                         } else {
-                            i += 1;
+                            return Err(ParseError {
+                                line: base_line + i + consumed + 1,
+                                column: indent + 1,
+                                message: format!(
+                                    "unexpected YAML structure in array element (partial {:?}). Problem line:\n'{}'",
+                                    child_map,
+                                    lines.get(i + consumed + 1).unwrap_or(&"<EOF>")
+                                ),
+                            });
                         }
-
-                        arr.push(Yaml::Hash(child_map));
-                        continue;
+                        i += consumed + 1;
+                    } else {
+                        i += 1;
                     }
-
-                    // Case 3: Simple scalar array element
-                    arr.push(Yaml::Value(val_str.to_string()));
+                } else {
                     i += 1;
-                    continue;
                 }
 
-                // --- REGULAR KEY: VALUE ---
-                if let Some(idx) = trimmed.find(':') {
-                    let key = trimmed[..idx].trim().to_string();
-                    let val_str = trimmed[idx + 1..].trim();
+                arr.push(Yaml::Hash(child_map));
+                continue;
+            }
+
+            // Case 3: Simple scalar array element, possibly `*alias` or
+            // `&anchor value`.
+            if let Some(alias) = val_str.strip_prefix('*') {
+                arr.push(Yaml::Alias(alias.trim().to_string()));
+            } else {
+                let (anchor, remainder) = split_anchor(val_str);
+                let node = Yaml::from_scalar(remainder);
+                arr.push(wrap_anchor(anchor, node));
+            }
+            i += 1;
+            continue;
+        }
+
+        // --- REGULAR KEY: VALUE ---
+        if let Some(idx) = trimmed.find(':') {
+            let key = trimmed[..idx].trim().to_string();
+            let val_str = trimmed[idx + 1..].trim();
 
+            #[cfg(debug_assertions)]
+            println!("🧾 Line {} (indent {}): Key '{}' => '{}'", i, indent, key, val_str);
+
+            // A duplicate key in the same mapping would silently shadow the
+            // first value (YamlMap::insert is "last value wins"), which
+            // breaks the byte-for-byte round-trip guarantee this parser is
+            // meant to give Unity documents — reject it instead.
+            if map.contains_key(&key) {
+                return Err(ParseError {
+                    line: base_line + i,
+                    column: indent + 1,
+                    message: format!("duplicate key '{}' in mapping", key),
+                });
+            }
+
+            if val_str.is_empty() {
+                #[cfg(debug_assertions)]
+                println!("  ↳ Nested block detected for key '{}'", key);
+                let (child, consumed) = parse_block(&lines[i + 1..], indent + 2, base_line + i + 1)?;
+                map.insert(key, child);
+                i += consumed + 1;
+            } else if let Some(alias) = val_str.strip_prefix('*') {
+                map.insert(key, Yaml::Alias(alias.trim().to_string()));
+                i += 1;
+            } else {
+                let (anchor, remainder) = split_anchor(val_str);
+                if anchor.is_some() && remainder.is_empty() {
+                    // `key: &anchor` — the anchor labels the nested block below.
                     #[cfg(debug_assertions)]
-                    println!("🧾 Line {} (indent {}): Key '{}' => '{}'", i, indent, key, val_str);
+                    println!("  ↳ Anchored nested block detected for key '{}'", key);
+                    let (child, consumed) =
+                        parse_block(&lines[i + 1..], indent + 2, base_line + i + 1)?;
+                    map.insert(key, wrap_anchor(anchor, child));
+                    i += consumed + 1;
+                } else if remainder.starts_with('{') && remainder.ends_with('}') {
+                    map.insert(key, wrap_anchor(anchor, parse_inline_mapping(remainder)));
+                    i += 1;
+                } else {
+                    map.insert(key, wrap_anchor(anchor, Yaml::from_scalar(remainder)));
+                    i += 1;
+                }
+            }
+            continue;
+        }
 
-                    if val_str.is_empty() {
-                        #[cfg(debug_assertions)]
-                        println!("  ↳ Nested block detected for key '{}'", key);
-                        let (child, consumed) = parse_block(&lines[i + 1..], indent + 2);
-                        map.insert(key, child);
+        #[cfg(debug_assertions)]
+        println!("⚠️  Unrecognized line {}: '{}'", i, trimmed);
+        i += 1;
+    }
+
+    let arr_len= arr.len();
+    let hash_len = map.len();
+
+    let ret_arr = Yaml::Array(arr);
+    let ret_hash = Yaml::Hash(map);
+
+    #[cfg(debug_assertions)]
+    {
+    println!(
+        "🏁 EXIT block (indent {}) as {} with {} entries",
+        start_indent,
+        if is_array { "Array" } else { "Hash" },
+        if is_array { arr_len } else { hash_len }
+    );
+    println!("And we collected the hash as:\n{}\nand the array as \n{}", ret_hash, ret_arr );
+    }
+    if is_array {
+        Ok((ret_arr, i))
+    } else {
+        Ok((ret_hash, i))
+    }
+}
+
+/// Strict-mode counterpart to `parse_block`, backing `load_strict_from_str`.
+/// Shares the same indentation-driven block structure, but every scalar is
+/// taken verbatim as a `Yaml::Value` string, and flow collections, anchors,
+/// aliases, and tags are rejected outright rather than handled.
+fn parse_block_strict(lines: &[&str], start_indent: usize, base_line: usize) -> Result<(Yaml, usize), ParseError> {
+    let mut map: YamlMap = YamlMap::new();
+    let mut arr: Vec<Yaml> = Vec::new();
+    let mut is_array = false;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let indent = line.chars().take_while(|c| *c == ' ').count();
+
+        if indent < start_indent {
+            break;
+        }
+
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            i += 1;
+            continue;
+        }
+
+        if let Some(val_str) = trimmed.strip_prefix('-') {
+            is_array = true;
+            let val_str = val_str.trim();
+
+            if val_str.is_empty() {
+                return Err(ParseError {
+                    line: base_line + i,
+                    column: indent + 1,
+                    message: format!(
+                        "array element with nested block below is not yet supported: '{}'",
+                        line
+                    ),
+                });
+            }
+
+            if let Some(idx) = val_str.find(':') {
+                let key = val_str[..idx].trim().to_string();
+                let value = val_str[idx + 1..].trim();
+                reject_unsafe_subset(value, base_line + i, indent + 1)?;
+                let mut child_map: YamlMap = YamlMap::new();
+                child_map.insert(key, Yaml::Value(value.to_string()));
+
+                if i + 1 < lines.len() {
+                    let next_indent = lines[i + 1].chars().take_while(|c| *c == ' ').count();
+                    if next_indent > indent {
+                        let (nested, consumed) =
+                            parse_block_strict(&lines[i + 1..], indent + 2, base_line + i + 1)?;
+                        if let Yaml::Hash(nmap) = nested {
+                            for (k, v) in nmap {
+                                if child_map.contains_key(&k) {
+                                    return Err(ParseError {
+                                        line: base_line + i,
+                                        column: indent + 1,
+                                        message: format!("duplicate key '{}' in mapping", k),
+                                    });
+                                }
+                                child_map.insert(k, v);
+                            }
+                        }
                         i += consumed + 1;
-                    } else if val_str.starts_with('{') && val_str.ends_with('}') {
-                        map.insert(key, parse_inline_mapping(val_str));
-                        i += 1;
                     } else {
-                        map.insert(key, Yaml::Value(val_str.to_string()));
                         i += 1;
                     }
-                    continue;
+                } else {
+                    i += 1;
                 }
 
-                #[cfg(debug_assertions)]
-                println!("⚠️  Unrecognized line {}: '{}'", i, trimmed);
-                i += 1;
+                arr.push(Yaml::Hash(child_map));
+                continue;
             }
 
-            let arr_len= arr.len();
-            let hash_len = map.len();
+            reject_unsafe_subset(val_str, base_line + i, indent + 1)?;
+            arr.push(Yaml::Value(val_str.to_string()));
+            i += 1;
+            continue;
+        }
 
-            let ret_arr = Yaml::Array(arr);
-            let ret_hash = Yaml::Hash(map);
+        if let Some(idx) = trimmed.find(':') {
+            let key = trimmed[..idx].trim().to_string();
+            let val_str = trimmed[idx + 1..].trim();
 
-            #[cfg(debug_assertions)]
-            {
-            println!(
-                "🏁 EXIT block (indent {}) as {} with {} entries",
-                start_indent,
-                if is_array { "Array" } else { "Hash" },
-                if is_array { arr_len } else { hash_len }
-            );
-            println!("And we collected the hash as:\n{}\nand the array as \n{}", ret_hash, ret_arr );
-            }
-            if is_array {
-                (ret_arr, i)
+            if map.contains_key(&key) {
+                return Err(ParseError {
+                    line: base_line + i,
+                    column: indent + 1,
+                    message: format!("duplicate key '{}' in mapping", key),
+                });
+            }
+
+            if val_str.is_empty() {
+                let (child, consumed) = parse_block_strict(&lines[i + 1..], indent + 2, base_line + i + 1)?;
+                map.insert(key, child);
+                i += consumed + 1;
             } else {
-                (ret_hash, i)
+                reject_unsafe_subset(val_str, base_line + i, indent + 1)?;
+                map.insert(key, Yaml::Value(val_str.to_string()));
+                i += 1;
             }
+            continue;
         }
 
-        fn parse_inline_mapping(s: &str) -> Yaml {
-            let mut map = HashMap::new();
-            let inner = s.strip_prefix('{').and_then(|v| v.strip_suffix('}')).unwrap_or(s);
+        i += 1;
+    }
+
+    if is_array {
+        Ok((Yaml::Array(arr), i))
+    } else {
+        Ok((Yaml::Hash(map), i))
+    }
+}
+
+/// Reject the constructs `load_strict_from_str` disallows — flow-style
+/// collections, anchors, aliases, and tags — with a `ParseError` naming the
+/// offending line, rather than quietly handling them like `parse_block` does.
+fn reject_unsafe_subset(value: &str, line: usize, column: usize) -> Result<(), ParseError> {
+    let reason = if value.starts_with('{') || value.starts_with('[') {
+        Some("flow-style collections ('{...}'/'[...]') are not allowed in strict mode")
+    } else if value.starts_with('&') {
+        Some("anchors are not allowed in strict mode")
+    } else if value.starts_with('*') {
+        Some("aliases are not allowed in strict mode")
+    } else if value.starts_with('!') {
+        Some("tags are not allowed in strict mode")
+    } else {
+        None
+    };
+
+    match reason {
+        Some(message) => Err(ParseError { line, column, message: message.to_string() }),
+        None => Ok(()),
+    }
+}
+
+/// Strip matching `"..."`/`'...'` quotes off a scalar token, returning the
+/// inner text. Returns `None` for an unquoted (or mismatched-quote) token, so
+/// `from_scalar` can tell "keep this as a literal string" apart from
+/// "classify it normally".
+fn strip_matching_quotes(s: &str) -> Option<&str> {
+    if s.len() >= 2 {
+        if let Some(inner) = s.strip_prefix('"').and_then(|r| r.strip_suffix('"')) {
+            return Some(inner);
+        }
+        if let Some(inner) = s.strip_prefix('\'').and_then(|r| r.strip_suffix('\'')) {
+            return Some(inner);
+        }
+    }
+    None
+}
+
+/// Parse a `0x`/`0o`-prefixed (optionally signed) integer literal, per the
+/// YAML 1.2 core schema's `int` tag.
+fn parse_radix_int(s: &str) -> Option<i64> {
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (radix, digits) = if let Some(d) = unsigned.strip_prefix("0x").or_else(|| unsigned.strip_prefix("0X")) {
+        (16, d)
+    } else if let Some(d) = unsigned.strip_prefix("0o").or_else(|| unsigned.strip_prefix("0O")) {
+        (8, d)
+    } else {
+        return None;
+    };
+    let value = i64::from_str_radix(digits, radix).ok()?;
+    Some(if negative { -value } else { value })
+}
+
+/// `true` for anything the YAML 1.2 core schema's `float` tag accepts:
+/// ordinary decimal floats plus the `.inf`/`-.inf`/`.nan` special forms
+/// (case-insensitive, optionally signed).
+fn is_core_schema_float(s: &str) -> bool {
+    parse_core_schema_float(s).is_some()
+}
+
+/// Parse a float the way the YAML 1.2 core schema's `float` tag does: a
+/// plain decimal, or the `.inf`/`-.inf`/`.nan` special forms that Rust's own
+/// `f64::from_str` (which wants `inf`/`nan`, no leading dot) doesn't accept.
+fn parse_core_schema_float(s: &str) -> Option<f64> {
+    let lower = s.to_ascii_lowercase();
+    let (negative, unsigned) = match lower.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, lower.strip_prefix('+').unwrap_or(&lower)),
+    };
+    match unsigned {
+        ".inf" => return Some(if negative { f64::NEG_INFINITY } else { f64::INFINITY }),
+        ".nan" => return Some(f64::NAN),
+        _ => {}
+    }
+    s.parse().ok()
+}
+
+/// Split a value token on a leading `&anchor` label, returning the anchor
+/// name (if any) and the remaining text still to be parsed, e.g.
+/// `"&anchor_1 {x: 1}"` -> `(Some("anchor_1"), "{x: 1}")`. A value with no
+/// anchor returns `(None, value)` unchanged.
+fn split_anchor(value: &str) -> (Option<String>, &str) {
+    match value.strip_prefix('&') {
+        Some(rest) => {
+            let rest = rest.trim_start();
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let (name, remainder) = rest.split_at(end);
+            (Some(name.to_string()), remainder.trim_start())
+        }
+        None => (None, value),
+    }
+}
+
+/// Wrap `node` in `Yaml::Anchor` if an anchor name was found, otherwise
+/// return it unchanged.
+fn wrap_anchor(anchor: Option<String>, node: Yaml) -> Yaml {
+    match anchor {
+        Some(name) => Yaml::Anchor(name, Box::new(node)),
+        None => node,
+    }
+}
+
+/// Parse a `{key: value, ...}` inline mapping. This never fails in practice
+/// (a malformed entry is simply dropped rather than raising a structural
+/// error), unlike `parse_block`'s array/nesting cases.
+fn parse_inline_mapping(s: &str) -> Yaml {
+    let mut map = YamlMap::new();
+    let inner = s.strip_prefix('{').and_then(|v| v.strip_suffix('}')).unwrap_or(s);
+
+    #[cfg(debug_assertions)]
+    println!("🧩 Inline mapping: {}", inner);
+
+    for part in inner.split(',') {
+        let kv: Vec<&str> = part.splitn(2, ':').collect();
+        if kv.len() == 2 {
+            let k = kv[0].trim().to_string();
+            let v = kv[1].trim().to_string();
+            map.insert(k.clone(), Yaml::from_scalar(&v));
 
             #[cfg(debug_assertions)]
-            println!("🧩 Inline mapping: {}", inner);
+            println!("   ↳ Inline pair {}: {}", k, v);
+        }
+    }
+    Yaml::Hash(map)
+}
+
+/// Collects per-object warnings while importing a Unity scene/prefab, so a
+/// large import can be audited afterwards instead of silently dropping data.
+///
+/// Tracks two things concretely: ambiguous scalars flagged by
+/// `parse_unity_object_strict`, and unresolved fileIDs flagged by
+/// `FileIdResolver::audit`. Leading comments stripped by
+/// `parse_unity_object_strict` are kept verbatim in `leading_comments`
+/// rather than as a warning string, so they can be handed back to
+/// `prepend_leading_comments` and carried into emitted output.
+#[derive(Debug, Clone, Default)]
+pub struct ConversionReport {
+    pub warnings: Vec<String>,
+    pub leading_comments: Vec<String>,
+}
 
-            for part in inner.split(',') {
-                let kv: Vec<&str> = part.splitn(2, ':').collect();
-                if kv.len() == 2 {
-                    let k = kv[0].trim().to_string();
-                    let v = kv[1].trim().to_string();
-                    map.insert(k.clone(), Yaml::Value(v.clone()));
+impl ConversionReport {
+    pub fn new() -> Self {
+        ConversionReport::default()
+    }
 
-                    #[cfg(debug_assertions)]
-                    println!("   ↳ Inline pair {}: {}", k, v);
+    pub fn warn_unresolved_file_id(&mut self, file_id: i64) {
+        self.warnings
+            .push(format!("unresolved fileID {}", file_id));
+    }
+
+    pub fn warn_ambiguous_scalar(&mut self, key: &str, value: &str) {
+        self.warnings.push(format!(
+            "{}: ambiguous scalar '{}' (looks like a number/bool but kept as a string)",
+            key, value
+        ));
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Reattach the leading comments this report collected to the front of
+    /// `body` (typically the output of `YamlEmitter::emit`), so a strict
+    /// parse/re-emit round trip doesn't lose them.
+    pub fn prepend_leading_comments(&self, body: &str) -> String {
+        if self.leading_comments.is_empty() {
+            return body.to_string();
+        }
+        let mut out = self.leading_comments.join("\n");
+        out.push('\n');
+        out.push_str(body);
+        out
+    }
+}
+
+/// Scalars that strict mode flags as easy to misparse: Unity version-ish
+/// strings like `1.10` (trailing zero would be lost as a float), and the
+/// YAML 1.1 boolean spellings (`on`/`off`/`yes`/`no`) that this crate does
+/// not treat as booleans but a naive reader might.
+pub fn is_ambiguous_scalar(value: &str) -> bool {
+    let lower = value.to_ascii_lowercase();
+    if matches!(lower.as_str(), "on" | "off" | "yes" | "no") {
+        return true;
+    }
+    // A dotted numeric string with a trailing zero, e.g. "1.10": round-tripping
+    // through a float would silently become "1.1".
+    if let Some((_, frac)) = value.split_once('.') {
+        if !frac.is_empty() && frac.ends_with('0') && value.parse::<f64>().is_ok() {
+            return true;
+        }
+    }
+    false
+}
+
+/// Header information extracted from a Unity `--- !u!<classID> &<fileID>` line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnityObjectHeader {
+    pub class_id: u32,
+    pub file_id: i64,
+    /// Whether the header carried a trailing `stripped` flag, which Unity
+    /// writes on a prefab instance's placeholder documents (objects whose
+    /// real data lives in the prefab asset, not this file).
+    pub stripped: bool,
+}
+
+/// One document out of a Unity YAML stream: the `!u!<classID>`/`&<fileID>`
+/// header, the component's class name (the single key a Unity document's
+/// top-level mapping always has, e.g. `GameObject`, `Transform`,
+/// `MonoBehaviour`), and that key's value as the parsed body.
+#[derive(Debug, Clone)]
+pub struct UnityDocument {
+    pub class_id: u32,
+    pub file_id: i64,
+    pub stripped: bool,
+    pub class_name: String,
+    pub body: Yaml,
+}
+
+impl Yaml {
+    /// Split a Unity YAML stream (the contents of a `.unity`/`.prefab`/`.asset`
+    /// file) on its `--- !u!<classID> &<fileID>` document markers and parse
+    /// each document's body with `parse_unity_object`.
+    ///
+    /// Tolerates the `%YAML 1.1`/`%TAG !u! tag:unity3d.com,2011:` directives
+    /// at the top of the file (by skipping any line starting with `%`) and
+    /// the trailing `stripped` flag Unity adds to prefab-instance documents.
+    pub fn parse_unity_stream(text: &str) -> Result<Vec<UnityDocument>, ParseError> {
+        let mut documents = Vec::new();
+        let mut current_header: Option<UnityObjectHeader> = None;
+        let mut current_lines: Vec<&str> = Vec::new();
+
+        for line in text.lines() {
+            if line.trim_start().starts_with('%') {
+                continue;
+            }
+            if let Some(header) = parse_unity_document_marker(line) {
+                if let Some(prev) = current_header.take() {
+                    documents.push(finish_unity_document(prev, &current_lines)?);
                 }
+                current_lines.clear();
+                current_header = Some(header);
+            } else if current_header.is_some() {
+                current_lines.push(line);
             }
-            Yaml::Hash(map)
         }
 
-        #[cfg(debug_assertions)]
-        println!("🚀 Starting YAML parse of {} lines", lines.len());
+        if let Some(prev) = current_header.take() {
+            documents.push(finish_unity_document(prev, &current_lines)?);
+        }
 
-        let (yaml, _) = parse_block(lines, 0);
+        Ok(documents)
+    }
 
-        #[cfg(debug_assertions)]
-        println!("✅ Completed top-level parse");
+    /// Friendlier-named entry point for `parse_unity_stream`, mirroring
+    /// yaml-rust's `YamlLoader::load_from_str` but for a stream of tagged
+    /// Unity documents rather than a single generic YAML document.
+    pub fn load_all_from_str(text: &str) -> Result<Vec<UnityDocument>, ParseError> {
+        Self::parse_unity_stream(text)
+    }
 
-        yaml
+    /// Inverse of `parse_unity_stream`/`load_all_from_str`: re-serialize a
+    /// list of `UnityDocument`s into a valid Unity YAML stream, reproducing
+    /// each document's `--- !u!N &M` header line (plus ` stripped` when set)
+    /// exactly.
+    pub fn emit_unity_stream(documents: &[UnityDocument]) -> String {
+        let mut out = String::new();
+        out.push_str("%YAML 1.1\n%TAG !u! tag:unity3d.com,2011:\n");
+        for doc in documents {
+            out.push_str(&format!("--- !u!{} &{}", doc.class_id, doc.file_id));
+            if doc.stripped {
+                out.push_str(" stripped");
+            }
+            out.push('\n');
+            out.push_str(&doc.body.to_indented_string(&doc.class_name));
+        }
+        out
+    }
+}
+
+/// Parse a document's body lines and split its single top-level key (the
+/// Unity class name) from the fields nested under it.
+fn finish_unity_document(header: UnityObjectHeader, lines: &[&str]) -> Result<UnityDocument, ParseError> {
+    let parsed = Yaml::parse_unity_object(lines)?;
+    let (class_name, body) = match &parsed {
+        Yaml::Hash(map) if map.len() == 1 => {
+            let (k, v) = map.iter().next().expect("len == 1");
+            (k.clone(), v.clone())
+        }
+        _ => (String::new(), parsed),
+    };
+    Ok(UnityDocument {
+        class_id: header.class_id,
+        file_id: header.file_id,
+        stripped: header.stripped,
+        class_name,
+        body,
+    })
+}
+
+/// Parse a `--- !u!<classID> &<fileID> [stripped]` marker line.
+fn parse_unity_document_marker(line: &str) -> Option<UnityObjectHeader> {
+    let rest = line.trim().strip_prefix("---")?.trim();
+    let mut class_id = None;
+    let mut file_id = None;
+    let mut stripped = false;
+
+    for token in rest.split_whitespace() {
+        if let Some(id) = token.strip_prefix("!u!") {
+            class_id = id.parse::<u32>().ok();
+        } else if let Some(id) = token.strip_prefix('&') {
+            file_id = id.parse::<i64>().ok();
+        } else if token == "stripped" {
+            stripped = true;
+        }
+    }
+
+    match (class_id, file_id) {
+        (Some(class_id), Some(file_id)) => Some(UnityObjectHeader { class_id, file_id, stripped }),
+        _ => None,
+    }
+}
+
+/// Resolves Unity `{fileID: N}` references to the object they point at,
+/// built from the output of `Yaml::parse_unity_stream`.
+pub struct FileIdResolver {
+    objects: HashMap<i64, Yaml>,
+}
+
+impl FileIdResolver {
+    /// Index a parsed document stream by fileID.
+    pub fn from_stream(documents: &[UnityDocument]) -> Self {
+        let mut objects = HashMap::new();
+        for doc in documents {
+            objects.insert(doc.file_id, doc.body.clone());
+        }
+        FileIdResolver { objects }
+    }
+
+    /// Follow a `{fileID: N}` (or `{fileID: N, guid: ..., type: ...}`)
+    /// reference to the object it points at. Returns `None` when the
+    /// reference carries a `guid` — that marks an external `ext_resource`
+    /// asset that doesn't live in this document — or when the fileID isn't
+    /// in this stream (including fileID `0`, Unity's "no reference" value).
+    pub fn resolve(&self, reference: &Yaml) -> Option<&Yaml> {
+        let Yaml::Hash(map) = reference else {
+            return None;
+        };
+        if map.contains_key("guid") {
+            return None;
+        }
+        let file_id = map.get("fileID")?.as_i64()?;
+        if file_id == 0 {
+            return None;
+        }
+        self.objects.get(&file_id)
+    }
+
+    /// Walk every document's body for internal `{fileID: N}` references
+    /// (those without a `guid`, i.e. not an `ext_resource`) and record one
+    /// `warn_unresolved_file_id` per reference that doesn't resolve against
+    /// this stream, so a scene/prefab import can be audited for dangling
+    /// references instead of only discovering them via a later panic.
+    pub fn audit(&self, documents: &[UnityDocument]) -> ConversionReport {
+        let mut report = ConversionReport::new();
+        for doc in documents {
+            self.audit_node(&doc.body, &mut report);
+        }
+        report
+    }
+
+    fn audit_node(&self, node: &Yaml, report: &mut ConversionReport) {
+        match node {
+            Yaml::Hash(map) => {
+                if map.contains_key("fileID") && self.resolve(node).is_none() {
+                    if let Some(file_id) = map.get("fileID").and_then(Yaml::as_i64) {
+                        if file_id != 0 && !map.contains_key("guid") {
+                            report.warn_unresolved_file_id(file_id);
+                        }
+                    }
+                }
+                for value in map.values() {
+                    self.audit_node(value, report);
+                }
+            }
+            Yaml::Array(items) => {
+                for item in items {
+                    self.audit_node(item, report);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Serializes a `Yaml` tree back into YAML text — the write-side counterpart
+/// to `parse_unity_object`. Unlike the `Display` impl (which is the plain
+/// Unity-document writer `save_to_file` has always used and never quotes
+/// anything), this quotes any `Value` scalar whose unquoted form would
+/// reparse as something else, and can optionally write flat hashes/arrays
+/// (see `Yaml::is_flat_hash`/`is_flat_array`) as `{...}`/`[...]` flow
+/// collections instead of indented block style.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct YamlEmitter {
+    flow_style: bool,
+}
+
+impl YamlEmitter {
+    pub fn new() -> Self {
+        YamlEmitter::default()
+    }
+
+    /// Write flat (all-scalar) hashes and arrays as `{...}`/`[...]` rather
+    /// than one field/element per line.
+    pub fn with_flow_style(mut self, flow_style: bool) -> Self {
+        self.flow_style = flow_style;
+        self
+    }
+
+    /// Serialize `yaml` to a YAML string.
+    pub fn emit(&self, yaml: &Yaml) -> String {
+        let mut out = String::new();
+        self.emit_node(yaml, 0, &mut out);
+        out
+    }
+
+    fn emit_node(&self, yaml: &Yaml, indent: usize, out: &mut String) {
+        let indent_str = "  ".repeat(indent);
+        match yaml {
+            Yaml::Hash(map) if map.is_empty() => out.push_str("{}\n"),
+            Yaml::Hash(map) => {
+                for (k, v) in map {
+                    self.emit_entry(&indent_str, &format!("{}:", k), v, indent, out);
+                }
+            }
+            Yaml::Array(items) if items.is_empty() => out.push_str("[]\n"),
+            Yaml::Array(items) => {
+                for item in items {
+                    self.emit_entry(&indent_str, "-", item, indent, out);
+                }
+            }
+            _ => {
+                out.push_str(&self.emit_scalar(yaml));
+                out.push('\n');
+            }
+        }
+    }
+
+    /// Emit one `key:`/`-` line, handling an `&anchor` label on the value
+    /// and deciding between inline and nested-block layout for it.
+    fn emit_entry(&self, indent_str: &str, prefix: &str, value: &Yaml, indent: usize, out: &mut String) {
+        let (anchor, inner) = match value {
+            Yaml::Anchor(name, inner) => (Some(name.as_str()), inner.as_ref()),
+            other => (None, other),
+        };
+
+        if inner.is_scalar() || (self.flow_style && (inner.is_flat_hash() || inner.is_flat_array())) {
+            let rendered = if inner.is_scalar() {
+                self.emit_scalar(inner)
+            } else {
+                self.emit_flow(inner)
+            };
+            match anchor {
+                Some(name) => out.push_str(&format!("{}{} &{} {}\n", indent_str, prefix, name, rendered)),
+                None => out.push_str(&format!("{}{} {}\n", indent_str, prefix, rendered)),
+            }
+        } else {
+            match anchor {
+                Some(name) => out.push_str(&format!("{}{} &{}\n", indent_str, prefix, name)),
+                None => out.push_str(&format!("{}{}\n", indent_str, prefix)),
+            }
+            self.emit_node(inner, indent + 1, out);
+        }
+    }
+
+    fn emit_flow(&self, yaml: &Yaml) -> String {
+        match yaml {
+            Yaml::Hash(map) => {
+                let parts: Vec<String> = map
+                    .iter()
+                    .map(|(k, v)| format!("{}: {}", k, self.emit_scalar(v)))
+                    .collect();
+                format!("{{{}}}", parts.join(", "))
+            }
+            Yaml::Array(items) => {
+                let parts: Vec<String> = items.iter().map(|v| self.emit_scalar(v)).collect();
+                format!("[{}]", parts.join(", "))
+            }
+            other => self.emit_scalar(other),
+        }
+    }
+
+    fn emit_scalar(&self, yaml: &Yaml) -> String {
+        match yaml {
+            Yaml::Value(s) => quote_scalar_if_needed(s),
+            Yaml::Alias(name) => format!("*{}", name),
+            other => format!("{}", other),
+        }
+    }
+}
+
+/// Quote a `Value` scalar's text if writing it unquoted would change its
+/// meaning on reparse: looking like a number/bool/null, starting with a YAML
+/// indicator character, or containing a `": "` that would be misread as a
+/// mapping separator.
+fn quote_scalar_if_needed(s: &str) -> String {
+    let needs_quoting = s.is_empty()
+        || !matches!(Yaml::from_scalar(s), Yaml::Value(ref v) if v == s)
+        || matches!(
+            s.chars().next(),
+            Some('-' | '&' | '*' | '!' | '#' | '{' | '[' | '"' | '\'' | '|' | '>' | '%' | '@' | '`')
+        )
+        || s.contains(": ")
+        || s.ends_with(':');
+
+    if needs_quoting {
+        format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        s.to_string()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
     use std::fs;
 
     #[test]
     fn test_yaml_array_save_and_load_roundtrip() {
-        use std::collections::HashMap;
         use std::fs;
 
         // 🧩 1️⃣ Build an array inside a hash
@@ -531,7 +1673,7 @@ mod tests {
             Yaml::Value("three".to_string()),
         ]);
 
-        let mut outer = HashMap::new();
+        let mut outer = YamlMap::new();
         outer.insert("values".to_string(), array);
         let yaml = Yaml::Hash(outer);
 
@@ -567,7 +1709,7 @@ mod tests {
     #[test]
     fn test_yaml_save_and_load_roundtrip() {
         // 🧩 1. Create a small test YAML structure
-        let mut map = HashMap::new();
+        let mut map = YamlMap::new();
         map.insert("guidA".to_string(), Yaml::Value("res://textures/UI/Button.png".to_string()));
         map.insert("guidB".to_string(), Yaml::Value("res://materials/Metal.tres".to_string()));
         let yaml = Yaml::Hash(map);
@@ -610,7 +1752,7 @@ m_Modifications:
 "#;
 
         let lines: Vec<&str> = yaml_text.lines().collect();
-        let parsed = Yaml::parse_unity_object(&lines);
+        let parsed = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
         let parsed_str = format!("{}", parsed);
         // Check that top-level is a Hash
         if let Yaml::Hash(map) = parsed {
@@ -655,11 +1797,381 @@ m_Modifications:
 
     #[test]
     fn test_get_str_from_hash() {
-        let mut map = HashMap::new();
+        let mut map = YamlMap::new();
         map.insert("name".to_string(), Yaml::Value("Alice".to_string()));
         let yaml = Yaml::Hash(map);
 
         assert_eq!(yaml.get_str("name"), Some("Alice"));
         assert_eq!(yaml.get_str("missing"), None);
     }
+
+    #[test]
+    fn test_from_scalar_classifies_typed_values() {
+        assert!(matches!(Yaml::from_scalar(""), Yaml::Null));
+        assert!(matches!(Yaml::from_scalar("~"), Yaml::Null));
+        assert!(matches!(Yaml::from_scalar("null"), Yaml::Null));
+        assert_eq!(Yaml::from_scalar("true").as_bool(), Some(true));
+        assert_eq!(Yaml::from_scalar("false").as_bool(), Some(false));
+        assert_eq!(Yaml::from_scalar("42").as_i64(), Some(42));
+        assert_eq!(Yaml::from_scalar("0.2").as_f64(), Some(0.2));
+        assert!(matches!(Yaml::from_scalar("Canvas"), Yaml::Value(_)));
+    }
+
+    #[test]
+    fn test_parse_unity_object_parses_typed_scalars() {
+        let yaml_text = r#"
+m_IsActive: 1
+m_Enabled: true
+m_Name:
+"#;
+        let lines: Vec<&str> = yaml_text.lines().collect();
+        let parsed = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        if let Yaml::Hash(map) = parsed {
+            assert_eq!(map["m_IsActive"].as_i64(), Some(1));
+            assert_eq!(map["m_Enabled"].as_bool(), Some(true));
+        } else {
+            panic!("expected top-level Hash");
+        }
+    }
+
+    #[test]
+    fn test_hash_preserves_insertion_order_on_roundtrip() {
+        let yaml_text = r#"
+m_Name: Canvas
+m_LocalPosition:
+  x: 0
+  y: 0
+  z: 0
+m_IsActive: 1
+"#;
+        let lines: Vec<&str> = yaml_text.lines().collect();
+        let parsed = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let first_pass = format!("{}", parsed);
+
+        // Re-parse the re-emitted text: if key order weren't preserved, a
+        // HashMap-backed Hash would shuffle keys between the two passes.
+        let reparsed_lines: Vec<&str> = first_pass.lines().collect();
+        let reparsed = Yaml::parse_unity_object(&reparsed_lines).expect("re-parse failed");
+        let second_pass = format!("{}", reparsed);
+
+        assert_eq!(first_pass, second_pass);
+
+        if let Yaml::Hash(map) = &parsed {
+            let keys: Vec<&String> = map.keys().collect();
+            assert_eq!(keys, vec!["m_Name", "m_LocalPosition", "m_IsActive"]);
+        } else {
+            panic!("expected top-level Hash");
+        }
+    }
+
+    #[test]
+    fn test_parse_unity_stream_splits_documents_and_resolves_file_ids() {
+        let scene_text = r#"%YAML 1.1
+%TAG !u! tag:unity3d.com,2011:
+--- !u!1 &100000
+GameObject:
+  m_Name: Player
+--- !u!4 &100001
+Transform:
+  m_GameObject: {fileID: 100000}
+  m_LocalPosition: {x: 0, y: 0, z: 0}
+"#;
+        let documents = Yaml::parse_unity_stream(scene_text).expect("parse_unity_stream failed");
+        assert_eq!(documents.len(), 2);
+        assert_eq!(documents[0].class_id, 1);
+        assert_eq!(documents[0].file_id, 100000);
+        assert_eq!(documents[0].class_name, "GameObject");
+        assert_eq!(documents[1].class_name, "Transform");
+
+        let resolver = FileIdResolver::from_stream(&documents);
+        let game_object_ref = match &documents[1].body {
+            Yaml::Hash(map) => map.get("m_GameObject").cloned().expect("m_GameObject field missing"),
+            _ => panic!("expected Transform body to be a Hash"),
+        };
+        let resolved = resolver.resolve(&game_object_ref).expect("fileID should resolve");
+        assert_eq!(resolved.get_str("m_Name"), Some("Player"));
+    }
+
+    #[test]
+    fn test_stream_round_trip_preserves_stripped_flag() {
+        let prefab_text = r#"%YAML 1.1
+%TAG !u! tag:unity3d.com,2011:
+--- !u!1 &100000 stripped
+GameObject:
+  m_Name: Player
+--- !u!4 &100001
+Transform:
+  m_LocalPosition: {x: 0, y: 0, z: 0}
+"#;
+        let documents = Yaml::load_all_from_str(prefab_text).expect("load_all_from_str failed");
+        assert_eq!(documents.len(), 2);
+        assert!(documents[0].stripped);
+        assert!(!documents[1].stripped);
+
+        let emitted = Yaml::emit_unity_stream(&documents);
+        assert!(emitted.contains("--- !u!1 &100000 stripped\n"));
+        assert!(emitted.contains("--- !u!4 &100001\n"));
+
+        let reparsed = Yaml::load_all_from_str(&emitted).expect("re-parsing emitted stream failed");
+        assert!(reparsed[0].stripped);
+        assert!(!reparsed[1].stripped);
+    }
+
+    #[test]
+    fn test_parse_and_resolve_anchors_and_aliases() {
+        let yaml_text = r#"base: &base_settings
+  m_Name: Default
+  m_IsActive: 1
+override: *base_settings
+"#;
+        let lines: Vec<&str> = yaml_text.lines().collect();
+        let parsed = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+
+        let Yaml::Hash(map) = &parsed else {
+            panic!("expected top-level Hash");
+        };
+        assert!(matches!(map.get("base"), Some(Yaml::Anchor(name, _)) if name == "base_settings"));
+        assert!(matches!(map.get("override"), Some(Yaml::Alias(name)) if name == "base_settings"));
+
+        // The unresolved tree still round-trips through Display, anchor intact.
+        let reprinted = format!("{}", parsed);
+        assert!(reprinted.contains("&base_settings"));
+        assert!(reprinted.contains("*base_settings"));
+
+        let resolved = parsed.resolve_aliases().expect("resolve_aliases failed");
+        let Yaml::Hash(resolved_map) = &resolved else {
+            panic!("expected resolved top-level Hash");
+        };
+        assert_eq!(resolved_map.get("override").unwrap().get_str("m_Name"), Some("Default"));
+    }
+
+    #[test]
+    fn test_resolve_aliases_rejects_self_reference_as_forward_reference() {
+        // A self-referential anchor is, by document order, an alias to a
+        // name that hasn't finished being defined yet — rejected the same
+        // way an ordinary forward reference is, without ever recursing.
+        let mut inner = YamlMap::new();
+        inner.insert("next".to_string(), Yaml::Alias("a".to_string()));
+        let self_referential = Yaml::Anchor("a".to_string(), Box::new(Yaml::Hash(inner)));
+
+        let err = self_referential
+            .resolve_aliases()
+            .expect_err("self-referential anchor should fail to resolve");
+        assert!(err.message.contains("hasn't been defined yet"));
+    }
+
+    #[test]
+    fn test_resolve_aliases_rejects_forward_references_and_honors_last_definition() {
+        let forward_ref_text = "base: *later\nlater: &later value\n";
+        let lines: Vec<&str> = forward_ref_text.lines().collect();
+        let parsed = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let err = parsed
+            .resolve_aliases()
+            .expect_err("alias before its anchor's definition should fail to resolve");
+        assert!(err.message.contains("hasn't been defined yet"));
+
+        let redefinition_text = "first: &shared one\nsecond: &shared two\nthird: *shared\n";
+        let lines: Vec<&str> = redefinition_text.lines().collect();
+        let parsed = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+        let resolved = parsed.resolve_aliases().expect("resolve_aliases failed");
+        assert_eq!(resolved.get_str("third"), Some("two"));
+    }
+
+    #[test]
+    fn test_index_chains_return_badvalue_instead_of_panicking() {
+        let yaml_text = r#"GameObject:
+  m_Component:
+    - component: {fileID: 222000}
+  m_IsActive: 1
+"#;
+        let lines: Vec<&str> = yaml_text.lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+
+        assert_eq!(
+            doc["GameObject"]["m_Component"][0]["component"]["fileID"].as_i64(),
+            Some(222000)
+        );
+        // Missing keys, wrong-kind nodes, and out-of-bounds indices all hand
+        // back BadValue rather than panicking.
+        assert!(doc["NoSuchKey"].is_badvalue());
+        assert!(doc["GameObject"]["m_Component"][99].is_badvalue());
+        assert!(doc["GameObject"]["m_IsActive"]["nested"].is_badvalue());
+        assert_eq!(doc["NoSuchKey"]["deeper"].as_i64(), None);
+        assert!(!doc["NoSuchKey"]["m_IsActive"].as_bool().unwrap_or(false));
+    }
+
+    #[test]
+    fn test_index_chain_as_str_on_prefab_modifications() {
+        let yaml_text = r#"PrefabInstance:
+  m_Modifications:
+    - target: {fileID: 400000, guid: abc123, type: 3}
+      propertyPath: m_Name
+      value: Player
+      objectReference: {fileID: 0}
+"#;
+        let lines: Vec<&str> = yaml_text.lines().collect();
+        let doc = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+
+        assert_eq!(
+            doc["PrefabInstance"]["m_Modifications"][0]["propertyPath"].as_str(),
+            Some("m_Name")
+        );
+        // A missing/out-of-range hop anywhere in the chain still type-checks
+        // and bottoms out at BadValue, so as_str() just returns None.
+        assert_eq!(
+            doc["PrefabInstance"]["m_Modifications"][5]["objectReference"].as_str(),
+            None
+        );
+        assert_eq!(
+            doc["PrefabInstance"]["m_Modifications"][0]["noSuchField"].as_str(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_unity_object_strict_forces_ambiguous_scalars_to_stay_strings() {
+        let lines: Vec<&str> = vec!["m_Name: 1.10", "m_Flag: on"];
+        let (yaml, report) = Yaml::parse_unity_object_strict(&lines).expect("parse_unity_object_strict failed");
+
+        assert_eq!(yaml.get_str("m_Name"), Some("1.10"));
+        assert_eq!(yaml.get_str("m_Flag"), Some("on"));
+        assert!(report.warnings.iter().any(|w| w.contains("m_Name") && w.contains("kept as a string")));
+        assert!(report.warnings.iter().any(|w| w.contains("m_Flag") && w.contains("kept as a string")));
+    }
+
+    #[test]
+    fn test_parse_unity_object_strict_carries_leading_comments_into_emitted_output() {
+        let lines: Vec<&str> = vec!["# exported by hand, do not regenerate", "m_Name: Player"];
+        let (yaml, report) = Yaml::parse_unity_object_strict(&lines).expect("parse_unity_object_strict failed");
+
+        assert_eq!(report.leading_comments, vec!["# exported by hand, do not regenerate".to_string()]);
+        assert_eq!(yaml.get_str("m_Name"), Some("Player"));
+
+        let emitted = YamlEmitter::new().emit(&yaml);
+        let round_tripped = report.prepend_leading_comments(&emitted);
+        assert!(round_tripped.starts_with("# exported by hand, do not regenerate\n"));
+        assert!(round_tripped.contains("m_Name: Player"));
+    }
+
+    #[test]
+    fn test_file_id_resolver_audit_flags_unresolved_internal_references() {
+        let scene_text = r#"%YAML 1.1
+%TAG !u! tag:unity3d.com,2011:
+--- !u!4 &100001
+Transform:
+  m_GameObject: {fileID: 999999}
+  m_LocalPosition: {x: 0, y: 0, z: 0}
+"#;
+        let documents = Yaml::parse_unity_stream(scene_text).expect("parse_unity_stream failed");
+        let resolver = FileIdResolver::from_stream(&documents);
+
+        let report = resolver.audit(&documents);
+        assert!(report.warnings.iter().any(|w| w.contains("999999")));
+    }
+
+    #[test]
+    fn test_load_strict_from_str_keeps_every_scalar_a_string() {
+        let text = "name: on\nport: 8080\nenabled: true\n";
+        let doc = Yaml::load_strict_from_str(text).expect("load_strict_from_str failed");
+        assert_eq!(doc.get_str("name"), Some("on"));
+        assert_eq!(doc.get_str("port"), Some("8080"));
+        assert_eq!(doc.get_str("enabled"), Some("true"));
+    }
+
+    #[test]
+    fn test_load_strict_from_str_rejects_duplicate_keys() {
+        let text = "name: a\nname: b\n";
+        let err = Yaml::load_strict_from_str(text).expect_err("expected duplicate key error");
+        assert!(err.message.contains("duplicate key 'name'"));
+    }
+
+    #[test]
+    fn test_load_strict_from_str_rejects_flow_anchors_and_aliases() {
+        let flow_err = Yaml::load_strict_from_str("settings: {a: 1}\n").expect_err("expected flow rejection");
+        assert!(flow_err.message.contains("flow-style"));
+
+        let anchor_err = Yaml::load_strict_from_str("base: &defaults\n  a: 1\n").expect_err("expected anchor rejection");
+        assert!(anchor_err.message.contains("anchors"));
+
+        let alias_err = Yaml::load_strict_from_str("override: *defaults\n").expect_err("expected alias rejection");
+        assert!(alias_err.message.contains("aliases"));
+    }
+
+    #[test]
+    fn test_from_scalar_core_schema_extensions() {
+        assert_eq!(Yaml::from_scalar("0x1A").as_i64(), Some(26));
+        assert_eq!(Yaml::from_scalar("-0x1A").as_i64(), Some(-26));
+        assert_eq!(Yaml::from_scalar("0o17").as_i64(), Some(15));
+        assert_eq!(Yaml::from_scalar(".inf").as_f64(), Some(f64::INFINITY));
+        assert_eq!(Yaml::from_scalar("-.inf").as_f64(), Some(f64::NEG_INFINITY));
+        assert!(Yaml::from_scalar(".nan").as_f64().is_some_and(f64::is_nan));
+
+        // Quoted scalars stay String no matter what their contents look like.
+        assert!(matches!(Yaml::from_scalar("\"42\""), Yaml::Value(ref s) if s == "42"));
+        assert!(matches!(Yaml::from_scalar("'true'"), Yaml::Value(ref s) if s == "true"));
+        assert!(matches!(Yaml::from_scalar("\"null\""), Yaml::Value(ref s) if s == "null"));
+    }
+
+    #[test]
+    fn test_parse_unity_object_rejects_duplicate_keys() {
+        let yaml_text = "m_Name: Canvas\nm_IsActive: 1\nm_Name: Overwritten\n";
+        let lines: Vec<&str> = yaml_text.lines().collect();
+        let err = Yaml::parse_unity_object(&lines).expect_err("duplicate key should be rejected");
+        assert!(err.message.contains("duplicate key 'm_Name'"));
+        assert_eq!(err.line, 3);
+    }
+
+    #[test]
+    fn test_yaml_emitter_round_trip_is_stable() {
+        let yaml_text = r#"m_Name: Canvas
+m_IsActive: 1
+m_LocalPosition:
+  x: 0
+  y: 1.5
+  z: 0
+m_Tags:
+  - Untagged
+  - Respawn
+"#;
+        let lines: Vec<&str> = yaml_text.lines().collect();
+        let parsed = Yaml::parse_unity_object(&lines).expect("parse_unity_object failed");
+
+        let emitter = YamlEmitter::new();
+        let emitted_once = emitter.emit(&parsed);
+        let reparsed_lines: Vec<&str> = emitted_once.lines().collect();
+        let reparsed = Yaml::parse_unity_object(&reparsed_lines).expect("re-parse of emitted text failed");
+        let emitted_twice = emitter.emit(&reparsed);
+
+        assert_eq!(emitted_once, emitted_twice);
+    }
+
+    #[test]
+    fn test_yaml_emitter_quotes_ambiguous_scalars() {
+        let mut map = YamlMap::new();
+        map.insert("m_Script".to_string(), Yaml::Value("42".to_string()));
+        map.insert("m_Note".to_string(), Yaml::Value("plain text".to_string()));
+        let yaml = Yaml::Hash(map);
+
+        let emitted = YamlEmitter::new().emit(&yaml);
+        assert!(emitted.contains("m_Script: \"42\""));
+        assert!(emitted.contains("m_Note: plain text"));
+
+        // The quoted "42" must reparse as a String, not an Integer.
+        let lines: Vec<&str> = emitted.lines().collect();
+        let reparsed = Yaml::parse_unity_object(&lines).expect("re-parse failed");
+        assert!(matches!(reparsed.get_str("m_Script"), Some("42")));
+    }
+
+    #[test]
+    fn test_yaml_emitter_flow_style_for_flat_collections() {
+        let mut inner = YamlMap::new();
+        inner.insert("x".to_string(), Yaml::Integer(0));
+        inner.insert("y".to_string(), Yaml::Integer(1));
+        let mut map = YamlMap::new();
+        map.insert("m_LocalPosition".to_string(), Yaml::Hash(inner));
+        let yaml = Yaml::Hash(map);
+
+        let emitted = YamlEmitter::new().with_flow_style(true).emit(&yaml);
+        assert_eq!(emitted, "m_LocalPosition: {x: 0, y: 1}\n");
+    }
 }
\ No newline at end of file